@@ -270,7 +270,7 @@ define_Conf! {
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, MANUAL_HASH_ONE, ITER_KV_MAP, MANUAL_C_STR_LITERALS, ASSIGNING_CLONES, LEGACY_NUMERIC_CONSTANTS, MANUAL_PATTERN_CHAR_COMPARISON, ALLOW_ATTRIBUTES, ALLOW_ATTRIBUTES_WITHOUT_REASON.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, OPTION_MAP_UNWRAP_OR, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, TYPE_REPETITION_IN_BOUNDS, TUPLE_ARRAY_CONVERSIONS, MANUAL_TRY_FOLD, MANUAL_HASH_ONE, ITER_KV_MAP, MANUAL_C_STR_LITERALS, ASSIGNING_CLONES, LEGACY_NUMERIC_CONSTANTS, MANUAL_PATTERN_CHAR_COMPARISON, ALLOW_ATTRIBUTES, ALLOW_ATTRIBUTES_WITHOUT_REASON, PREFER_ONCE_LOCK.
     ///
     /// The minimum rust version that the project supports. Defaults to the `rust-version` field in `Cargo.toml`
     #[default_text = ""]
@@ -369,6 +369,12 @@ define_Conf! {
     ///
     /// The maximum allowed size for arrays on the stack
     (array_size_threshold: u64 = 512_000),
+    /// Lint: LARGE_STACK_ARRAYS.
+    ///
+    /// The maximum allowed size (in bytes) for local arrays. By default `array-size-threshold` is
+    /// used, which also applies to `large_const_arrays`
+    #[default_text = ""]
+    (max_stack_array_bytes: Option<u64> = None),
     /// Lint: LARGE_STACK_FRAMES.
     ///
     /// The maximum allowed stack size for functions in bytes
@@ -645,6 +651,83 @@ define_Conf! {
     ///
     /// Whether to also emit warnings for unsafe blocks with metavariable expansions in **private** macros.
     (warn_unsafe_macro_metavars_in_private_macros: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to only check mutations of fields that are visible outside of their module, such as `pub`
+    /// or `pub(crate)` fields. Restrictions on private fields are ignored.
+    (fields_mutated_by_whitelist_public_only: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to require every field visible outside of its module to have a `#[clippy::mutatedby(...)]`
+    /// attribute. Fields any function may mutate can be marked with `#[clippy::mutatedby("*")]`.
+    (fields_mutated_by_whitelist_require_annotation: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to also check writes through a `&mut` returned by a function allowed to mutate the field,
+    /// e.g. `*value.field_mut() = 1;`.
+    (fields_mutated_by_whitelist_check_accessors: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// The number of methods of a type mutating a field per method that doesn't, above which listing the
+    /// methods that don't in a `#[clippy::not_mutatedby(...)]` attribute is suggested instead.
+    (fields_mutated_by_whitelist_blacklist_ratio: u64 = 4),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// The attribute listing the functions allowed to mutate a field. Names without a path refer to a `clippy::`
+    /// attribute, other crates' tool attributes are given by their path, e.g. `my_tool::mutatedby`.
+    (fields_mutated_by_whitelist_attribute_name: String = "mutatedby".to_owned()),
+    /// Lint: BULK_ALLOW_ATTRIBUTE.
+    ///
+    /// The maximum number of Clippy lints a single `allow` or `expect` attribute may list. `expect`
+    /// attributes count the same as `allow` attributes, and each attribute is counted on its own.
+    (max_lints_per_allow_attribute: u64 = 3),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to suggest removing a disallowed assignment when it is the only statement of its block, e.g. a
+    /// branch setting the field. The suggestion is never applied automatically.
+    (fields_mutated_by_whitelist_suggest_removal: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to emit the lint as an error wherever it would otherwise be a warning. The lint still needs to be
+    /// enabled, and `#[allow]` or `#[expect]` still silence it.
+    (fields_mutated_by_whitelist_as_error: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to warn about `transmute` calls producing a `&mut` in functions accessing a field they aren't
+    /// allowed to mutate, as the reference may alias the field. This is a conservative check, which also warns
+    /// about references unrelated to the field.
+    (fields_mutated_by_whitelist_check_transmute: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to allow `#[test]` functions and functions inside `#[cfg(test)]` modules to mutate restricted
+    /// fields, so tests can set up state without being listed in the attribute.
+    (fields_mutated_by_whitelist_allow_test_fns: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to allow the `drop` method of a `Drop` impl to mutate the fields of its type, which it may need to
+    /// during teardown.
+    (fields_mutated_by_whitelist_allow_drop: bool = true),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Experimental: whether the functions listed in the attribute are treated as entry points, allowing the
+    /// local functions they call directly to mutate the field as well. Functions further down the call chain
+    /// are still checked.
+    (fields_mutated_by_whitelist_allow_callees: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to report all disallowed mutations of a field in a single warning on the field's attribute, listing
+    /// the mutating functions, instead of one warning per mutation.
+    (fields_mutated_by_whitelist_group_by_field: bool = false),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// The maximum length of the restriction attribute with the mutating function added to it, up to which this
+    /// is suggested. Longer attributes only get a help message naming the function.
+    (fields_mutated_by_whitelist_max_suggestion_length: u64 = 100),
+    /// Lint: FIELDS_MUTATED_BY_WHITELIST.
+    ///
+    /// Whether to only check mutations by functions in the module defining the field, trusting code in other
+    /// modules to go through the module's API.
+    (fields_mutated_by_whitelist_same_module_only: bool = false),
 }
 
 /// Search for the configuration file.
@@ -1,15 +1,18 @@
-use clippy_utils::consts::{constant, Constant};
-use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::is_inside_always_const_context;
-use clippy_utils::macros::{find_assert_args, root_macro_call_first_node, PanicExpn};
-use rustc_hir::{Expr, ExprKind};
-use rustc_lint::{LateContext, LateLintPass};
+use clippy_utils::consts::{constant, constant_simple, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{find_assert_args, find_assert_eq_args, root_macro_call_first_node, PanicExpn};
+use clippy_utils::source::snippet_opt;
+use clippy_utils::{is_in_test, is_inside_always_const_context};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_session::declare_lint_pass;
-use rustc_span::sym;
+use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for `assert!(true)` and `assert!(false)` calls.
+    /// Checks for `assert!(true)` and `assert!(false)` calls, as well as `assert_eq!` and
+    /// `assert_ne!` calls comparing two identical literals outside of tests.
     ///
     /// ### Why is this bad?
     /// Will be optimized out by the compiler or should probably be replaced by a
@@ -21,6 +24,7 @@ declare_clippy_lint! {
     /// assert!(true)
     /// const B: bool = false;
     /// assert!(B)
+    /// assert_eq!(1, 1)
     /// ```
     #[clippy::version = "1.34.0"]
     pub ASSERTIONS_ON_CONSTANTS,
@@ -30,54 +34,143 @@ declare_clippy_lint! {
 
 declare_lint_pass!(AssertionsOnConstants => [ASSERTIONS_ON_CONSTANTS]);
 
+#[derive(Clone, Copy, PartialEq)]
+enum AssertKind {
+    Assert,
+    AssertEq,
+    AssertNe,
+}
+
 impl<'tcx> LateLintPass<'tcx> for AssertionsOnConstants {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         let Some(macro_call) = root_macro_call_first_node(cx, e) else {
             return;
         };
-        let is_debug = match cx.tcx.get_diagnostic_name(macro_call.def_id) {
-            Some(sym::debug_assert_macro) => true,
-            Some(sym::assert_macro) => false,
+        let (kind, is_debug) = match cx.tcx.get_diagnostic_name(macro_call.def_id) {
+            Some(sym::debug_assert_macro) => (AssertKind::Assert, true),
+            Some(sym::assert_macro) => (AssertKind::Assert, false),
+            Some(sym::debug_assert_eq_macro) => (AssertKind::AssertEq, true),
+            Some(sym::assert_eq_macro) => (AssertKind::AssertEq, false),
+            Some(sym::debug_assert_ne_macro) => (AssertKind::AssertNe, true),
+            Some(sym::assert_ne_macro) => (AssertKind::AssertNe, false),
             _ => return,
         };
-        let Some((condition, panic_expn)) = find_assert_args(cx, e, macro_call.expn) else {
-            return;
+        let (args, panic_expn) = if kind == AssertKind::Assert {
+            let Some((condition, panic_expn)) = find_assert_args(cx, e, macro_call.expn) else {
+                return;
+            };
+            (vec![condition], panic_expn)
+        } else {
+            let Some((left, right, panic_expn)) = find_assert_eq_args(cx, e, macro_call.expn) else {
+                return;
+            };
+            (vec![left, right], panic_expn)
         };
-        let Some(Constant::Bool(val)) = constant(cx, cx.typeck_results(), condition) else {
+        // Tests pinning down values are expected to compare constants
+        if kind != AssertKind::Assert && is_in_test(cx.tcx, e.hir_id) {
+            return;
+        }
+        let Some(val) = assertion_value(cx, kind, &args) else {
             return;
         };
 
-        match condition.kind {
-            ExprKind::Path(..) | ExprKind::Lit(_) => {},
-            _ if is_inside_always_const_context(cx.tcx, e.hir_id) => return,
-            _ => {},
+        if !args
+            .iter()
+            .all(|arg| matches!(arg.kind, ExprKind::Path(..) | ExprKind::Lit(_)))
+            && is_inside_always_const_context(cx.tcx, e.hir_id)
+        {
+            return;
         }
 
+        let name = cx.tcx.item_name(macro_call.def_id);
+        // Named constants may have a different value depending on the configuration
+        let applicability = if args
+            .iter()
+            .all(|arg| constant_simple(cx, cx.typeck_results(), arg).is_some())
+        {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        let can_suggest = !macro_call.span.from_expansion();
+
         if val {
-            span_lint_and_help(
-                cx,
-                ASSERTIONS_ON_CONSTANTS,
-                macro_call.span,
-                format!(
-                    "`{}!(true)` will be optimized out by the compiler",
-                    cx.tcx.item_name(macro_call.def_id)
-                ),
-                None,
-                "remove it",
-            );
+            let msg = if kind == AssertKind::Assert {
+                format!("`{name}!(true)` will be optimized out by the compiler")
+            } else {
+                format!("this `{name}!` always succeeds and will be optimized out by the compiler")
+            };
+            span_lint_and_then(cx, ASSERTIONS_ON_CONSTANTS, macro_call.span, msg, |diag| {
+                if !can_suggest {
+                    diag.help("remove it");
+                } else if let Node::Stmt(_) = cx.tcx.parent_hir_node(e.hir_id)
+                    && let Some(semi_span) = cx.sess().source_map().mac_call_stmt_semi_span(macro_call.span)
+                {
+                    diag.span_suggestion(macro_call.span.to(semi_span), "remove it", "", applicability);
+                } else {
+                    diag.span_suggestion(macro_call.span, "remove it", "()", applicability);
+                }
+            });
         } else if !is_debug {
             let (assert_arg, panic_arg) = match panic_expn {
                 PanicExpn::Empty => ("", ""),
                 _ => (", ..", ".."),
             };
-            span_lint_and_help(
-                cx,
-                ASSERTIONS_ON_CONSTANTS,
-                macro_call.span,
-                format!("`assert!(false{assert_arg})` should probably be replaced"),
-                None,
-                format!("use `panic!({panic_arg})` or `unreachable!({panic_arg})`"),
-            );
+            let msg = if kind == AssertKind::Assert {
+                format!("`assert!(false{assert_arg})` should probably be replaced")
+            } else {
+                format!("this `{name}!` always fails and should probably be replaced")
+            };
+            span_lint_and_then(cx, ASSERTIONS_ON_CONSTANTS, macro_call.span, msg, |diag| {
+                let help = format!("use `panic!({panic_arg})` or `unreachable!({panic_arg})`");
+                if can_suggest && let Some(sugg) = panic_sugg(cx, args[args.len() - 1], macro_call.span, &panic_expn) {
+                    diag.span_suggestion(macro_call.span, help, sugg, applicability);
+                } else {
+                    diag.help(help);
+                }
+            });
         }
     }
 }
+
+/// Evaluates whether the assertion always succeeds. `assert_eq!` and `assert_ne!` are only
+/// evaluated when comparing two identical literals, as other constant arguments, e.g. named
+/// constants, are usually there to pin down their value. Floating point values are not compared,
+/// as `NaN` is not equal to itself.
+fn assertion_value(cx: &LateContext<'_>, kind: AssertKind, args: &[&Expr<'_>]) -> Option<bool> {
+    let eval = |arg: &Expr<'_>| constant(cx, cx.typeck_results(), arg);
+    match (kind, args) {
+        (AssertKind::Assert, [condition]) => match eval(condition)? {
+            Constant::Bool(val) => Some(val),
+            _ => None,
+        },
+        (AssertKind::AssertEq | AssertKind::AssertNe, [left, right])
+            if let ExprKind::Lit(_) = left.kind
+                && let ExprKind::Lit(_) = right.kind =>
+        {
+            let left = eval(left)?;
+            (matches!(
+                left,
+                Constant::Int(_) | Constant::Bool(_) | Constant::Char(_) | Constant::Str(_)
+            ) && left == eval(right)?)
+            .then_some(kind == AssertKind::AssertEq)
+        },
+        _ => None,
+    }
+}
+
+/// Builds the `panic!` call replacing an assertion which always fails, keeping its message.
+fn panic_sugg(
+    cx: &LateContext<'_>,
+    last_arg: &Expr<'_>,
+    call_span: Span,
+    panic_expn: &PanicExpn<'_>,
+) -> Option<String> {
+    if let PanicExpn::Empty = panic_expn {
+        return Some("panic!(\"assertion always fails\")".to_owned());
+    }
+    // The message follows the last asserted argument, e.g. `, "message {}", value)`
+    let rest = snippet_opt(cx, last_arg.span.shrink_to_hi().with_hi(call_span.hi()))?;
+    let message = rest.trim_start().strip_prefix(',')?.trim_start().strip_suffix(')')?;
+    Some(format!("panic!({message})"))
+}
@@ -0,0 +1,39 @@
+use super::{Attribute, BULK_ALLOW_ATTRIBUTE};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::is_from_proc_macro;
+use rustc_ast::NestedMetaItem;
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_span::sym;
+use rustc_span::symbol::Symbol;
+
+pub(super) fn check<'cx>(
+    cx: &LateContext<'cx>,
+    name: Symbol,
+    items: &[NestedMetaItem],
+    attr: &'cx Attribute,
+    max_lints: u64,
+) {
+    // Only Clippy lints are counted, `reason = ".."` and rustc lints are ignored
+    let clippy_lints = items
+        .iter()
+        .filter_map(NestedMetaItem::meta_item)
+        .filter(|item| item.path.segments.len() == 2 && item.path.segments[0].ident.name == sym::clippy)
+        .count();
+
+    if clippy_lints as u64 <= max_lints || in_external_macro(cx.sess(), attr.span) || is_from_proc_macro(cx, attr) {
+        return;
+    }
+
+    span_lint_and_help(
+        cx,
+        BULK_ALLOW_ATTRIBUTE,
+        attr.span,
+        format!(
+            "`{}` attribute suppressing {clippy_lints} Clippy lints at once",
+            name.as_str()
+        ),
+        None,
+        "split it into one attribute per lint, each with a comment explaining why the lint is suppressed",
+    );
+}
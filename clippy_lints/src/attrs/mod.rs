@@ -3,6 +3,7 @@
 mod allow_attributes;
 mod allow_attributes_without_reason;
 mod blanket_clippy_restriction_lints;
+mod bulk_allow_attribute;
 mod deprecated_cfg_attr;
 mod deprecated_semver;
 mod duplicated_attributes;
@@ -10,6 +11,7 @@ mod empty_line_after;
 mod inline_always;
 mod mixed_attributes_style;
 mod non_minimal_cfg;
+mod redundant_allow_unused_variables;
 mod should_panic_without_expect;
 mod unnecessary_clippy_cfg;
 mod useless_attribute;
@@ -18,7 +20,7 @@ mod utils;
 use clippy_config::msrvs::{self, Msrv};
 use clippy_config::Conf;
 use rustc_ast::{Attribute, MetaItemKind, NestedMetaItem};
-use rustc_hir::{ImplItem, Item, ItemKind, TraitItem};
+use rustc_hir::{ImplItem, ImplItemKind, Item, ItemKind, TraitItem};
 use rustc_lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass};
 use rustc_session::impl_lint_pass;
 use rustc_span::sym;
@@ -500,8 +502,63 @@ declare_clippy_lint! {
     "duplicated attribute"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `allow` and `expect` attributes listing more Clippy lints than configured by
+    /// `max-lints-per-allow-attribute`.
+    ///
+    /// ### Why restrict this?
+    /// An attribute suppressing many lints at once rarely comes with a justification for each
+    /// of them, which makes it hard to review and to tell which of them are still needed.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_lossless, clippy::cast_precision_loss)]
+    /// fn foo() {}
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// // `x` is known to be in `0..=255`
+    /// #[allow(clippy::cast_possible_truncation)]
+    /// // `x` is never negative
+    /// #[allow(clippy::cast_sign_loss)]
+    /// fn foo() {}
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub BULK_ALLOW_ATTRIBUTE,
+    restriction,
+    "`allow` and `expect` attributes suppressing many Clippy lints at once"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `#[allow(unused_variables)]` on functions and impls where every variable is
+    /// either used or has a name starting with `_`.
+    ///
+    /// ### Why is this bad?
+    /// The `_` prefix already silences the lint for the unused variables, so the attribute has
+    /// no effect. It would however hide a variable which becomes unused later on.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// #[allow(unused_variables)]
+    /// fn f(_unused: u32) {}
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// fn f(_unused: u32) {}
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub REDUNDANT_ALLOW_UNUSED_VARIABLES,
+    pedantic,
+    "`#[allow(unused_variables)]` on an item without unused variables"
+}
+
 pub struct Attributes {
     msrv: Msrv,
+    max_lints_per_allow_attribute: u64,
 }
 
 impl_lint_pass!(Attributes => [
@@ -514,12 +571,15 @@ impl_lint_pass!(Attributes => [
     SHOULD_PANIC_WITHOUT_EXPECT,
     MIXED_ATTRIBUTES_STYLE,
     DUPLICATED_ATTRIBUTES,
+    BULK_ALLOW_ATTRIBUTE,
+    REDUNDANT_ALLOW_UNUSED_VARIABLES,
 ]);
 
 impl Attributes {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
             msrv: conf.msrv.clone(),
+            max_lints_per_allow_attribute: conf.max_lints_per_allow_attribute,
         }
     }
 }
@@ -543,6 +603,9 @@ impl<'tcx> LateLintPass<'tcx> for Attributes {
                 {
                     allow_attributes_without_reason::check(cx, ident.name, items, attr);
                 }
+                if matches!(ident.name, sym::allow | sym::expect) {
+                    bulk_allow_attribute::check(cx, ident.name, items, attr, self.max_lints_per_allow_attribute);
+                }
                 if items.is_empty() || !attr.has_name(sym::deprecated) {
                     return;
                 }
@@ -568,6 +631,7 @@ impl<'tcx> LateLintPass<'tcx> for Attributes {
         }
         match item.kind {
             ItemKind::ExternCrate(..) | ItemKind::Use(..) => useless_attribute::check(cx, item, attrs),
+            ItemKind::Fn(..) | ItemKind::Impl(..) => redundant_allow_unused_variables::check_item(cx, item, attrs),
             _ => {},
         }
         mixed_attributes_style::check(cx, item.span, attrs);
@@ -575,8 +639,12 @@ impl<'tcx> LateLintPass<'tcx> for Attributes {
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        let attrs = cx.tcx.hir().attrs(item.hir_id());
         if is_relevant_impl(cx, item) {
-            inline_always::check(cx, item.span, item.ident.name, cx.tcx.hir().attrs(item.hir_id()));
+            inline_always::check(cx, item.span, item.ident.name, attrs);
+        }
+        if let ImplItemKind::Fn(..) = item.kind {
+            redundant_allow_unused_variables::check_impl_item(cx, item, attrs);
         }
     }
 
@@ -0,0 +1,131 @@
+use super::{Attribute, REDUNDANT_ALLOW_UNUSED_VARIABLES};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::{IntoSpan, SpanRangeExt};
+use clippy_utils::{is_from_proc_macro, path_to_local};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::{walk_expr, walk_pat, Visitor};
+use rustc_hir::{Expr, ExprKind, HirId, ImplItem, Item, Pat, PatKind};
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::lint::in_external_macro;
+use rustc_span::sym;
+use rustc_span::symbol::kw;
+
+pub(super) fn check_item<'tcx>(cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>, attrs: &[Attribute]) {
+    if let Some(attr) = allow_unused_variables(attrs)
+        && !item.span.from_expansion()
+    {
+        let mut visitor = BindingVisitor::new(cx);
+        visitor.visit_item(item);
+        check(cx, attr, &visitor);
+    }
+}
+
+pub(super) fn check_impl_item<'tcx>(cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>, attrs: &[Attribute]) {
+    if let Some(attr) = allow_unused_variables(attrs)
+        && !item.span.from_expansion()
+    {
+        let mut visitor = BindingVisitor::new(cx);
+        visitor.visit_impl_item(item);
+        check(cx, attr, &visitor);
+    }
+}
+
+/// Finds an `#[allow(unused_variables)]` attribute, which doesn't allow any other lint.
+fn allow_unused_variables(attrs: &[Attribute]) -> Option<&Attribute> {
+    attrs.iter().find(|attr| {
+        if attr.has_name(sym::allow)
+            && let Some(items) = attr.meta_item_list()
+            && let [item] = &*items
+            && let Some(item) = item.meta_item()
+        {
+            item.is_word()
+                && item.path.segments.len() == 1
+                && item.path.segments[0].ident.as_str() == "unused_variables"
+        } else {
+            false
+        }
+    })
+}
+
+fn check(cx: &LateContext<'_>, attr: &Attribute, visitor: &BindingVisitor<'_, '_>) {
+    if visitor.from_expansion
+        || !visitor.bindings.iter().all(|id| visitor.used.contains(id))
+        || in_external_macro(cx.sess(), attr.span)
+        || is_from_proc_macro(cx, attr)
+    {
+        return;
+    }
+
+    // Remove the line of the attribute along with it
+    let span = attr
+        .span
+        .map_range(cx, |src, range| {
+            let rest = src.get(range.end..)?;
+            Some(range.start..range.end + rest.len() - rest.trim_start().len())
+        })
+        .map_or(attr.span, |range| range.with_ctxt(attr.span.ctxt()));
+    span_lint_and_sugg(
+        cx,
+        REDUNDANT_ALLOW_UNUSED_VARIABLES,
+        span,
+        "`#[allow(unused_variables)]` on an item without unused variables",
+        "remove the attribute",
+        String::new(),
+        Applicability::MachineApplicable,
+    );
+}
+
+/// Collects the bindings not starting with `_`, except `self`, and the locals which are read.
+struct BindingVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    bindings: Vec<HirId>,
+    used: FxHashSet<HirId>,
+    /// Whether a binding comes from a macro expansion, which may or may not be used.
+    from_expansion: bool,
+}
+
+impl<'a, 'tcx> BindingVisitor<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'tcx>) -> Self {
+        Self {
+            cx,
+            bindings: Vec::new(),
+            used: FxHashSet::default(),
+            from_expansion: false,
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for BindingVisitor<'_, 'tcx> {
+    type NestedFilter = nested_filter::All;
+
+    fn visit_pat(&mut self, pat: &'tcx Pat<'tcx>) {
+        if let PatKind::Binding(_, id, ident, _) = pat.kind {
+            if pat.span.from_expansion() {
+                self.from_expansion = true;
+            } else if ident.name != kw::SelfLower && !ident.as_str().starts_with('_') {
+                self.bindings.push(id);
+            }
+        }
+        walk_pat(self, pat);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        // A variable which is only assigned to is reported as unused as well
+        if let ExprKind::Assign(lhs, rhs, _) | ExprKind::AssignOp(_, lhs, rhs) = expr.kind
+            && path_to_local(lhs).is_some()
+        {
+            self.visit_expr(rhs);
+            return;
+        }
+        if let Some(id) = path_to_local(expr) {
+            self.used.insert(id);
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+}
@@ -0,0 +1,70 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{is_expr_path_def_path, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::print::with_forced_trimmed_paths;
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Box::pin(x)` where the type of `x` implements `Unpin`.
+    ///
+    /// ### Why is this bad?
+    /// `Pin` only restricts moving values which aren't `Unpin`. A pinned `Unpin` value can still
+    /// be moved out, so `Pin<Box<T>>` doesn't guarantee more than `Box<T>`, while being harder to
+    /// work with.
+    ///
+    /// ### Known problems
+    /// The `Pin<Box<T>>` may be required by the code the value is passed to, e.g. a function
+    /// taking a `Pin<Box<T>>` parameter, in which case the signature has to change as well.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let boxed = Box::pin(5);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let boxed = Box::new(5);
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub BOX_PIN_UNPIN_TYPE,
+    pedantic,
+    "`Box::pin` on a type implementing `Unpin`"
+}
+
+declare_lint_pass!(BoxPinUnpinType => [BOX_PIN_UNPIN_TYPE]);
+
+impl<'tcx> LateLintPass<'tcx> for BoxPinUnpinType {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && !expr.span.from_expansion()
+            && is_expr_path_def_path(cx, func, &paths::BOX_PIN)
+            // A `Pin<Box<dyn Trait>>` coerced from the result needs to stay pinned for the trait object
+            && cx.typeck_results().expr_adjustments(expr).is_empty()
+            && let Some(unpin_id) = cx.tcx.lang_items().get(LangItem::Unpin)
+            && let arg_ty = cx.typeck_results().expr_ty(arg)
+            && implements_trait(cx, arg_ty, unpin_id, &[])
+        {
+            span_lint_and_then(
+                cx,
+                BOX_PIN_UNPIN_TYPE,
+                expr.span,
+                format!(
+                    "pinning a value of type `{}`, which implements `Unpin`",
+                    with_forced_trimmed_paths!(arg_ty.to_string())
+                ),
+                |diag| {
+                    diag.span_suggestion(
+                        func.span,
+                        "if the value needs to be boxed, use `Box::new` instead",
+                        "Box::new",
+                        Applicability::MaybeIncorrect,
+                    );
+                    diag.help("otherwise, consider using the value without boxing it");
+                },
+            );
+        }
+    }
+}
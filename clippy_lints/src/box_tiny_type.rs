@@ -0,0 +1,73 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_def_id;
+use clippy_utils::source::snippet_with_context;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, Node, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Box::new(x)` where the boxed value is smaller than a pointer, such as a `u8`,
+    /// a `bool` or a `char`.
+    ///
+    /// ### Why restrict this?
+    /// The box itself is a pointer, so it takes more space than the value it stores, and creating
+    /// it requires a heap allocation. Storing the value directly is both smaller and faster.
+    ///
+    /// ### Known problems
+    /// Only boxes stored in a `let` binding without a type annotation are linted, as elsewhere the
+    /// type is usually dictated by the surrounding code. The lint can't tell whether the binding
+    /// is turned into a trait object or handed to an API requiring a `Box` later on.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let flag = Box::new(true);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let flag = true;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub BOX_TINY_TYPE,
+    restriction,
+    "boxing a value smaller than a pointer"
+}
+
+declare_lint_pass!(BoxTinyType => [BOX_TINY_TYPE]);
+
+impl LateLintPass<'_> for BoxTinyType {
+    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        if let ExprKind::Call(box_new, [arg]) = expr.kind
+            && let ExprKind::Path(QPath::TypeRelative(ty, seg)) = box_new.kind
+            && seg.ident.name == sym::new
+            && path_def_id(cx, ty).is_some_and(|id| Some(id) == cx.tcx.lang_items().owned_box())
+            && !in_external_macro(cx.sess(), expr.span)
+            // Elsewhere, e.g. in a function argument, a struct field or with a type annotation, the box is
+            // required by the surrounding code
+            && let Node::LetStmt(local) = cx.tcx.parent_hir_node(expr.hir_id)
+            && local.ty.is_none()
+            && let arg_ty = cx.typeck_results().expr_ty(arg)
+            && let Ok(layout) = cx.layout_of(arg_ty)
+            // Boxing a zero-sized value doesn't allocate
+            && !layout.is_zst()
+            && layout.size < cx.tcx.data_layout.pointer_size
+        {
+            span_lint_and_then(
+                cx,
+                BOX_TINY_TYPE,
+                expr.span,
+                format!("boxing a `{arg_ty}`, which is smaller than the pointer to it, allocates for no benefit"),
+                |diag| {
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let (snippet, _) = snippet_with_context(cx, arg.span, expr.span.ctxt(), "..", &mut applicability);
+                    diag.span_suggestion(expr.span, "store the value directly", snippet, applicability);
+                    diag.note("boxing is only needed if the value is used as a trait object");
+                },
+            );
+        }
+    }
+}
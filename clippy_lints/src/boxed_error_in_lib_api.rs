@@ -0,0 +1,122 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{is_in_cfg_test, return_ty};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, TraitItem, TraitItemKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, GenericArg, Ty};
+use rustc_session::config::CrateType;
+use rustc_session::declare_lint_pass;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for exported functions of library crates whose return type contains
+    /// `Box<dyn Error>`.
+    ///
+    /// ### Why is this bad?
+    /// Callers can't match on the errors of a `Box<dyn Error>` without downcasting, and every
+    /// error needs an allocation. A concrete error type, e.g. an enum with a variant for each
+    /// kind of failure, makes the possible errors part of the API.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::error::Error;
+    /// pub fn parse(input: &str) -> Result<u32, Box<dyn Error>> {
+    ///     Ok(input.parse()?)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::num::ParseIntError;
+    /// #[derive(Debug)]
+    /// pub enum ParseError {
+    ///     Int(ParseIntError),
+    /// }
+    /// # impl std::fmt::Display for ParseError {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { Ok(()) }
+    /// # }
+    /// impl std::error::Error for ParseError {}
+    ///
+    /// pub fn parse(input: &str) -> Result<u32, ParseError> {
+    ///     input.parse().map_err(ParseError::Int)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub BOXED_ERROR_IN_LIB_API,
+    restriction,
+    "exported function of a library returning `Box<dyn Error>`"
+}
+
+declare_lint_pass!(BoxedErrorInLibApi => [BOXED_ERROR_IN_LIB_API]);
+
+impl<'tcx> LateLintPass<'tcx> for BoxedErrorInLibApi {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'tcx>,
+        _: &'tcx Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        // The signature of a trait method is checked on the trait, by `check_trait_item` for the
+        // provided methods as well
+        if !matches!(kind, FnKind::Closure)
+            && cx.tcx.trait_of_item(def_id.to_def_id()).is_none()
+            && cx
+                .tcx
+                .impl_of_method(def_id.to_def_id())
+                .map_or(true, |impl_id| cx.tcx.trait_id_of_impl(impl_id).is_none())
+        {
+            check_fn_sig(cx, decl, def_id, span);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'tcx>) {
+        if let TraitItemKind::Fn(ref sig, _) = item.kind {
+            check_fn_sig(cx, sig.decl, item.owner_id.def_id, item.span);
+        }
+    }
+}
+
+fn check_fn_sig(cx: &LateContext<'_>, decl: &FnDecl<'_>, def_id: LocalDefId, span: Span) {
+    if is_library(cx)
+        && !in_external_macro(cx.sess(), span)
+        && cx.effective_visibilities.is_exported(def_id)
+        && !is_in_cfg_test(cx.tcx, cx.tcx.local_def_id_to_hir_id(def_id))
+        && return_ty(cx, cx.tcx.local_def_id_to_hir_id(def_id).expect_owner())
+            .walk()
+            .filter_map(GenericArg::as_type)
+            .any(|ty| is_boxed_error(cx, ty))
+    {
+        span_lint_and_help(
+            cx,
+            BOXED_ERROR_IN_LIB_API,
+            decl.output.span(),
+            "exported function returns a `Box<dyn Error>`",
+            None,
+            "consider defining an error type listing the possible errors, e.g. with the `thiserror` crate",
+        );
+    }
+}
+
+fn is_library(cx: &LateContext<'_>) -> bool {
+    cx.tcx
+        .crate_types()
+        .iter()
+        .any(|t| matches!(t, CrateType::Rlib | CrateType::Dylib))
+}
+
+/// Checks for `Box<dyn Error>`, with any auto traits, e.g. `Box<dyn Error + Send + Sync>`.
+fn is_boxed_error<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    if ty.is_box()
+        && let ty::Dynamic(preds, ..) = ty.boxed_ty().kind()
+        && let Some(principal) = preds.principal_def_id()
+    {
+        cx.tcx.is_diagnostic_item(sym::Error, principal)
+    } else {
+        false
+    }
+}
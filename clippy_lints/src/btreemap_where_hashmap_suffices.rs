@@ -0,0 +1,122 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::get_parent_expr;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, HirId, LetStmt, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for local `BTreeMap` bindings which are only used through operations that don't
+    /// depend on the order of the keys, such as `insert`, `get` and `remove`, while the key type
+    /// implements `Hash` and `Eq`.
+    ///
+    /// ### Why is this bad?
+    /// The operations of a `BTreeMap` take logarithmic time, while a `HashMap` does the same in
+    /// amortized constant time. The ordering of a `BTreeMap` is only worth paying for if it's used,
+    /// through `range`, `first_key_value` or iteration.
+    ///
+    /// ### Known problems
+    /// The lint only fires when the map isn't used in any other way, e.g. passed to a function,
+    /// since the other use might depend on the ordering.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::collections::BTreeMap;
+    /// let mut counts = BTreeMap::new();
+    /// *counts.entry("a").or_insert(0) += 1;
+    /// assert_eq!(counts.get("a"), Some(&1));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// let mut counts = HashMap::new();
+    /// *counts.entry("a").or_insert(0) += 1;
+    /// assert_eq!(counts.get("a"), Some(&1));
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub BTREEMAP_WHERE_HASHMAP_SUFFICES,
+    pedantic,
+    "`BTreeMap` whose ordering is never used, where a `HashMap` would suffice"
+}
+
+declare_lint_pass!(BTreeMapWhereHashMapSuffices => [BTREEMAP_WHERE_HASHMAP_SUFFICES]);
+
+/// The methods of `BTreeMap` which don't depend on the ordering of the keys.
+const UNORDERED_METHODS: &[&str] = &[
+    "insert",
+    "get",
+    "get_mut",
+    "get_key_value",
+    "contains_key",
+    "remove",
+    "remove_entry",
+    "entry",
+    "len",
+    "is_empty",
+    "clear",
+];
+
+impl<'tcx> LateLintPass<'tcx> for BTreeMapWhereHashMapSuffices {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
+        if let Some((local_id, init)) = hashable_btreemap_binding(cx, local)
+            && is_only_used_unordered(cx, local_id, init)
+        {
+            span_lint_and_help(
+                cx,
+                BTREEMAP_WHERE_HASHMAP_SUFFICES,
+                local.span,
+                "this `BTreeMap` is never used in a way which depends on the ordering of its keys",
+                None,
+                "consider using a `HashMap`",
+            );
+        }
+    }
+}
+
+/// Matches `let map = BTreeMap::new();` (or `default`) where the key type implements `Hash` and
+/// `Eq`, returning the binding and its initializer.
+fn hashable_btreemap_binding<'tcx>(cx: &LateContext<'tcx>, local: &LetStmt<'tcx>) -> Option<(HirId, &'tcx Expr<'tcx>)> {
+    if let PatKind::Binding(_, id, _, None) = local.pat.kind
+        && let Some(init) = local.init
+        && let ExprKind::Call(func, []) = init.kind
+        && let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind
+        && matches!(segment.ident.as_str(), "new" | "default")
+        && !local.span.from_expansion()
+        && let map_ty = cx.typeck_results().pat_ty(local.pat)
+        && is_type_diagnostic_item(cx, map_ty, sym::BTreeMap)
+        && let ty::Adt(_, args) = map_ty.kind()
+        && let Some(key_ty) = args.types().next()
+        && let Some(hash_id) = cx.tcx.get_diagnostic_item(sym::Hash)
+        && let Some(eq_id) = cx.tcx.get_diagnostic_item(sym::Eq)
+        && implements_trait(cx, key_ty, hash_id, &[])
+        && implements_trait(cx, key_ty, eq_id, &[])
+    {
+        Some((id, init))
+    } else {
+        None
+    }
+}
+
+/// Checks that the map is used at least once, and only as the receiver of methods which don't
+/// depend on the ordering.
+fn is_only_used_unordered(cx: &LateContext<'_>, local_id: HirId, init: &Expr<'_>) -> bool {
+    let mut is_used = false;
+    let res = for_each_local_use_after_expr(cx, local_id, init.hir_id, |e| {
+        if let Some(parent) = get_parent_expr(cx, e)
+            && let ExprKind::MethodCall(segment, receiver, ..) = parent.kind
+            && receiver.hir_id == e.hir_id
+            && UNORDERED_METHODS.contains(&segment.ident.as_str())
+        {
+            is_used = true;
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    });
+    res.is_continue() && is_used
+}
@@ -0,0 +1,106 @@
+use clippy_utils::consts::{constant_simple, Constant};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::{get_parent_expr, higher};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if` expressions whose condition is the literal `true` or `false`, or an
+    /// expression of literals that always evaluates to one of them.
+    ///
+    /// ### Why is this bad?
+    /// One of the branches is never executed. Either the condition is a leftover from debugging
+    /// or it does not check what the author intended it to.
+    ///
+    /// ### Known problems
+    /// Only conditions made of literals and operators on them are evaluated. Conditions using
+    /// named constants or coming from macros such as `cfg!(..)` are ignored, even though their
+    /// value is known at compile time, since it usually depends on the configuration.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # fn body() {}
+    /// # fn alt() {}
+    /// if true {
+    ///     body();
+    /// } else {
+    ///     alt();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn body() {}
+    /// {
+    ///     body();
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CONSTANT_IF_CONDITION,
+    pedantic,
+    "`if` expressions with a condition that is always `true` or always `false`"
+}
+
+declare_lint_pass!(ConstantIfCondition => [CONSTANT_IF_CONDITION]);
+
+impl<'tcx> LateLintPass<'tcx> for ConstantIfCondition {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(higher::If { cond, then, r#else }) = higher::If::hir(expr)
+            && !expr.span.from_expansion()
+            && let Some(value) = constant_condition(cx, cond)
+            // The suggestion for an outer `if` with a constant condition already covers this one
+            && !get_parent_expr(cx, expr).is_some_and(|parent| {
+                higher::If::hir(parent).is_some_and(|parent_if| {
+                    parent_if.r#else.is_some_and(|els| els.hir_id == expr.hir_id)
+                        && constant_condition(cx, parent_if.cond).is_some()
+                })
+            })
+        {
+            let mut applicability = Applicability::MachineApplicable;
+            let (span, help, sugg) = if value {
+                (
+                    expr.span,
+                    "replace the `if` expression with its `then` block",
+                    snippet_with_applicability(cx, then.span, "{ .. }", &mut applicability).into_owned(),
+                )
+            } else if let Some(els) = r#else {
+                (
+                    expr.span,
+                    "replace the `if` expression with its `else` branch",
+                    snippet_with_applicability(cx, els.span, "..", &mut applicability).into_owned(),
+                )
+            } else if let Node::Stmt(stmt) = cx.tcx.parent_hir_node(expr.hir_id) {
+                (stmt.span, "remove the `if` expression", String::new())
+            } else {
+                (expr.span, "remove the `if` expression", "()".to_owned())
+            };
+
+            span_lint_and_sugg(
+                cx,
+                CONSTANT_IF_CONDITION,
+                span,
+                format!("this `if` condition is always `{value}`"),
+                help,
+                sugg,
+                applicability,
+            );
+        }
+    }
+}
+
+/// Evaluates the condition if its value only depends on the expression itself. Named constants
+/// are commonly defined differently depending on the configuration or the target, so conditions
+/// using them are not considered constant.
+fn constant_condition(cx: &LateContext<'_>, cond: &Expr<'_>) -> Option<bool> {
+    if cond.span.from_expansion() {
+        return None;
+    }
+    if let Some(Constant::Bool(value)) = constant_simple(cx, cx.typeck_results(), cond) {
+        Some(value)
+    } else {
+        None
+    }
+}
@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::is_must_use_func_call;
 use clippy_utils::ty::{is_copy, is_must_use_ty, is_type_lang_item};
 use rustc_hir::{Arm, Expr, ExprKind, LangItem, Node};
@@ -63,6 +63,15 @@ declare_clippy_lint! {
     /// # use std::rc::Rc;
     /// mem::forget(Rc::new(55))
     /// ```
+    ///
+    /// Values whose destructor must not run on purpose can be wrapped in `ManuallyDrop` instead,
+    /// which makes the intent part of their type:
+    /// ```no_run
+    /// # use std::mem::ManuallyDrop;
+    /// # use std::rc::Rc;
+    /// // SAFETY: the value is handed over to the foreign library, which releases it
+    /// let value = ManuallyDrop::new(Rc::new(55));
+    /// ```
     #[clippy::version = "pre 1.29.0"]
     pub MEM_FORGET,
     restriction,
@@ -90,7 +99,7 @@ impl<'tcx> LateLintPass<'tcx> for DropForgetRef {
             let arg_ty = cx.typeck_results().expr_ty(arg);
             let is_copy = is_copy(cx, arg_ty);
             let drop_is_single_call_in_arm = is_single_call_in_arm(cx, arg, expr);
-            let (lint, msg, note_span) = match fn_name {
+            let (lint, msg, note_span, help) = match fn_name {
                 // early return for uplifted lints: dropping_references, dropping_copy_types, forgetting_references,
                 // forgetting_copy_types
                 sym::mem_drop if arg_ty.is_ref() && !drop_is_single_call_in_arm => return,
@@ -104,36 +113,45 @@ impl<'tcx> LateLintPass<'tcx> for DropForgetRef {
                         || is_must_use_ty(cx, arg_ty)
                         || drop_is_single_call_in_arm) =>
                 {
-                    (DROP_NON_DROP, DROP_NON_DROP_SUMMARY.into(), Some(arg.span))
+                    (DROP_NON_DROP, DROP_NON_DROP_SUMMARY.into(), Some(arg.span), None)
                 },
                 sym::mem_forget => {
                     if arg_ty.needs_drop(cx.tcx, cx.param_env) {
+                        let has_dtor = arg_ty.ty_adt_def().map_or(false, |def| def.has_dtor(cx.tcx));
                         (
                             MEM_FORGET,
                             Cow::Owned(format!(
                                 "usage of `mem::forget` on {}",
-                                if arg_ty.ty_adt_def().map_or(false, |def| def.has_dtor(cx.tcx)) {
+                                if has_dtor {
                                     "`Drop` type"
                                 } else {
                                     "type with `Drop` fields"
                                 }
                             )),
                             None,
+                            // Leaking the resources held by the value is rarely the intent
+                            has_dtor.then_some(
+                                "if the destructor must not run, wrap the value in `std::mem::ManuallyDrop` \
+                                 and document why",
+                            ),
                         )
                     } else {
-                        (FORGET_NON_DROP, FORGET_NON_DROP_SUMMARY.into(), Some(arg.span))
+                        (FORGET_NON_DROP, FORGET_NON_DROP_SUMMARY.into(), Some(arg.span), None)
                     }
                 },
                 _ => return,
             };
-            span_lint_and_note(
-                cx,
-                lint,
-                expr.span,
-                msg,
-                note_span,
-                format!("argument has type `{arg_ty}`"),
-            );
+            span_lint_and_then(cx, lint, expr.span, msg, |diag| {
+                let note = format!("argument has type `{arg_ty}`");
+                if let Some(note_span) = note_span {
+                    diag.span_note(note_span, note);
+                } else {
+                    diag.note(note);
+                }
+                if let Some(help) = help {
+                    diag.help(help);
+                }
+            });
         }
     }
 }
@@ -2,11 +2,12 @@ use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::macros::{find_format_arg_expr, root_macro_call_first_node, FormatArgsStorage};
 use clippy_utils::source::{snippet_opt, snippet_with_context};
 use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::peel_mid_ty_refs;
 use rustc_ast::{FormatArgsPiece, FormatOptions, FormatTrait};
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::ty;
+use rustc_middle::ty::{self, Ty};
 use rustc_session::impl_lint_pass;
 use rustc_span::{sym, Span};
 
@@ -88,15 +89,24 @@ impl<'tcx> LateLintPass<'tcx> for UselessFormat {
                             ExprKind::MethodCall(path, ..) => path.ident.name == sym::to_string,
                             _ => false,
                         };
-                        let sugg = if is_new_string {
-                            snippet_with_context(cx, value.span, call_site.ctxt(), "..", &mut applicability)
+                        if is_new_string {
+                            let sugg = snippet_with_context(cx, value.span, call_site.ctxt(), "..", &mut applicability)
                                 .0
-                                .into_owned()
+                                .into_owned();
+                            span_useless_format(cx, call_site, sugg, applicability);
                         } else {
+                            let method = string_conversion(cx, cx.typeck_results().expr_ty(value));
                             let sugg = Sugg::hir_with_context(cx, value, call_site.ctxt(), "<arg>", &mut applicability);
-                            format!("{}.to_string()", sugg.maybe_par())
-                        };
-                        span_useless_format(cx, call_site, sugg, applicability);
+                            span_lint_and_sugg(
+                                cx,
+                                USELESS_FORMAT,
+                                call_site,
+                                "useless use of `format!`",
+                                format!("consider using `.{method}()`"),
+                                format!("{}.{method}()", sugg.maybe_par()),
+                                applicability,
+                            );
+                        }
                     }
                 },
                 _ => {},
@@ -105,6 +115,17 @@ impl<'tcx> LateLintPass<'tcx> for UselessFormat {
     }
 }
 
+/// The method converting the argument to a `String`: `to_owned` for a `str` and `clone` for a
+/// `String`. Behind more references, these would return a reference, so `to_string` is used.
+fn string_conversion(cx: &LateContext<'_>, ty: Ty<'_>) -> &'static str {
+    let (inner, refs) = peel_mid_ty_refs(ty);
+    match inner.kind() {
+        ty::Str if refs <= 1 => "to_owned",
+        ty::Adt(adt, _) if refs <= 1 && Some(adt.did()) == cx.tcx.lang_items().string() => "clone",
+        _ => "to_string",
+    }
+}
+
 fn span_useless_format_empty(cx: &LateContext<'_>, span: Span, sugg: String, applicability: Applicability) {
     span_lint_and_sugg(
         cx,
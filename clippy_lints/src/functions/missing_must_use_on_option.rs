@@ -0,0 +1,51 @@
+use clippy_utils::attrs::is_proc_macro;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::indent_of;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{self as hir, QPath};
+use rustc_lint::{LateContext, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_span::sym;
+
+use super::{must_use, MISSING_MUST_USE_ON_OPTION};
+
+pub(super) fn check_item<'tcx>(cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
+    if let hir::ItemKind::Fn(ref sig, _generics, _) = item.kind
+        && returns_option(cx, sig.decl)
+        && cx.effective_visibilities.is_exported(item.owner_id.def_id)
+        && !item.ident.as_str().starts_with("try_")
+        && !in_external_macro(cx.sess(), item.span)
+        && cx.tcx.get_attr(item.owner_id, sym::must_use).is_none()
+        && !is_proc_macro(cx.tcx.hir().attrs(item.hir_id()))
+        && !must_use::is_linted_must_use_candidate(cx, item)
+    {
+        span_lint_and_then(
+            cx,
+            MISSING_MUST_USE_ON_OPTION,
+            item.span.with_hi(sig.decl.output.span().hi()),
+            "this function returns an `Option`, but has no `#[must_use]` attribute",
+            |diag| {
+                diag.span_suggestion_verbose(
+                    item.span.shrink_to_lo(),
+                    "add the attribute",
+                    format!("#[must_use]\n{}", " ".repeat(indent_of(cx, item.span).unwrap_or(0))),
+                    Applicability::MachineApplicable,
+                );
+            },
+        );
+    }
+}
+
+/// Whether the return type is written as `Option<..>`. Type aliases are not resolved, as they
+/// may be meant to hide what is returned.
+fn returns_option(cx: &LateContext<'_>, decl: &hir::FnDecl<'_>) -> bool {
+    if let hir::FnRetTy::Return(ty) = decl.output
+        && let hir::TyKind::Path(QPath::Resolved(None, path)) = ty.kind
+        && let Res::Def(DefKind::Enum, def_id) = path.res
+    {
+        cx.tcx.is_diagnostic_item(sym::Option, def_id)
+    } else {
+        false
+    }
+}
@@ -1,5 +1,6 @@
 mod impl_trait_in_params;
 mod misnamed_getters;
+mod missing_must_use_on_option;
 mod must_use;
 mod not_unsafe_ptr_arg_deref;
 mod renamed_function_params;
@@ -197,6 +198,41 @@ declare_clippy_lint! {
     "function or method that could take a `#[must_use]` attribute"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for public functions returning an `Option` which have no `#[must_use]` attribute.
+    /// Functions whose name starts with `try_` are not checked.
+    ///
+    /// ### Why is this bad?
+    /// Unlike `Result`, `Option` is not `#[must_use]`, so a caller ignoring the returned value is
+    /// not warned about it, even though computing the value is usually the point of the call.
+    ///
+    /// ### Known problems
+    /// Functions returning a `Result` are not linted, as ignoring it already triggers the
+    /// `unused_must_use` lint, and an empty `#[must_use]` attribute on them is reported by
+    /// `double_must_use`. The return type is checked as written, so type aliases of `Option` are
+    /// not linted either. Functions already reported by `must_use_candidate` are skipped when that
+    /// lint is enabled.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub fn first_even(values: &[u32]) -> Option<u32> {
+    ///     values.iter().copied().find(|v| v % 2 == 0)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// #[must_use]
+    /// pub fn first_even(values: &[u32]) -> Option<u32> {
+    ///     values.iter().copied().find(|v| v % 2 == 0)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MISSING_MUST_USE_ON_OPTION,
+    pedantic,
+    "public function returning an `Option` without a `#[must_use]` attribute"
+}
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for public functions that return a `Result`
@@ -432,6 +468,7 @@ impl_lint_pass!(Functions => [
     MUST_USE_UNIT,
     DOUBLE_MUST_USE,
     MUST_USE_CANDIDATE,
+    MISSING_MUST_USE_ON_OPTION,
     RESULT_UNIT_ERR,
     RESULT_LARGE_ERR,
     MISNAMED_GETTERS,
@@ -459,6 +496,7 @@ impl<'tcx> LateLintPass<'tcx> for Functions {
 
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
         must_use::check_item(cx, item);
+        missing_must_use_on_option::check_item(cx, item);
         result::check_item(cx, item, self.large_error_threshold);
     }
 
@@ -15,7 +15,7 @@ use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::is_must_use_ty;
 use clippy_utils::visitors::for_each_expr_without_closures;
-use clippy_utils::{return_ty, trait_ref_of_method};
+use clippy_utils::{is_lint_allowed, return_ty, trait_ref_of_method};
 
 use core::ops::ControlFlow;
 
@@ -144,13 +144,7 @@ fn check_must_use_candidate<'tcx>(
     fn_span: Span,
     msg: &'static str,
 ) {
-    if has_mutable_arg(cx, body)
-        || mutates_static(cx, body)
-        || in_external_macro(cx.sess(), item_span)
-        || returns_unit(decl)
-        || !cx.effective_visibilities.is_exported(item_id.def_id)
-        || is_must_use_ty(cx, return_ty(cx, item_id))
-    {
+    if !is_must_use_candidate(cx, decl, body, item_span, item_id) {
         return;
     }
     span_lint_and_then(cx, MUST_USE_CANDIDATE, fn_span, msg, |diag| {
@@ -165,6 +159,35 @@ fn check_must_use_candidate<'tcx>(
     });
 }
 
+fn is_must_use_candidate<'tcx>(
+    cx: &LateContext<'tcx>,
+    decl: &'tcx hir::FnDecl<'_>,
+    body: &'tcx hir::Body<'_>,
+    item_span: Span,
+    item_id: hir::OwnerId,
+) -> bool {
+    !(has_mutable_arg(cx, body)
+        || mutates_static(cx, body)
+        || in_external_macro(cx.sess(), item_span)
+        || returns_unit(decl)
+        || !cx.effective_visibilities.is_exported(item_id.def_id)
+        || is_must_use_ty(cx, return_ty(cx, item_id)))
+}
+
+/// Checks if `must_use_candidate` is enabled for the function item and lints it.
+pub(super) fn is_linted_must_use_candidate<'tcx>(cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) -> bool {
+    if let hir::ItemKind::Fn(ref sig, _generics, ref body_id) = item.kind {
+        let attrs = cx.tcx.hir().attrs(item.hir_id());
+        !is_lint_allowed(cx, MUST_USE_CANDIDATE, item.hir_id())
+            && cx.tcx.get_attr(item.owner_id, sym::must_use).is_none()
+            && !is_proc_macro(attrs)
+            && !attrs.iter().any(|a| a.has_name(sym::no_mangle))
+            && is_must_use_candidate(cx, sig.decl, cx.tcx.hir().body(*body_id), item.span, item.owner_id)
+    } else {
+        false
+    }
+}
+
 fn returns_unit(decl: &hir::FnDecl<'_>) -> bool {
     match decl.output {
         hir::FnRetTy::DefaultReturn(_) => true,
@@ -0,0 +1,135 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::get_parent_expr;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use core::ops::ControlFlow;
+use rustc_ast::LitKind;
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, LangItem, LetStmt, Mutability, PatKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for local `HashMap<String, _>` bindings where every key passed to `insert`, `get`,
+    /// `contains_key` and `remove` is a string literal, possibly converted with `String::from`,
+    /// `to_string`, `to_owned` or `into`.
+    ///
+    /// ### Why is this bad?
+    /// Every such key is allocated only to be compared or stored, while a `&'static str` key
+    /// would do the same without allocating.
+    ///
+    /// ### Known problems
+    /// The lint only fires when the map isn't used in any other way, since other uses might
+    /// depend on the key type.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// let mut map: HashMap<String, u32> = HashMap::new();
+    /// map.insert(String::from("one"), 1);
+    /// map.insert("two".to_string(), 2);
+    /// assert!(map.contains_key("one"));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// let mut map: HashMap<&'static str, u32> = HashMap::new();
+    /// map.insert("one", 1);
+    /// map.insert("two", 2);
+    /// assert!(map.contains_key("one"));
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub HASHMAP_STRING_LITERAL_KEY,
+    pedantic,
+    "`HashMap<String, _>` only ever used with string literal keys"
+}
+
+declare_lint_pass!(HashMapStringLiteralKey => [HASHMAP_STRING_LITERAL_KEY]);
+
+impl<'tcx> LateLintPass<'tcx> for HashMapStringLiteralKey {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
+        if let Some((local_id, init)) = string_keyed_map_binding(cx, local)
+            && has_only_literal_keys(cx, local_id, init)
+        {
+            span_lint_and_help(
+                cx,
+                HASHMAP_STRING_LITERAL_KEY,
+                local.span,
+                "this `HashMap` has `String` keys, but only ever uses string literals as keys",
+                None,
+                "consider using `&'static str` as the key type",
+            );
+        }
+    }
+}
+
+/// Matches `let map = HashMap::new();` (or `with_capacity`/`default`) where the map has `String`
+/// keys, returning the binding and its initializer.
+fn string_keyed_map_binding<'tcx>(cx: &LateContext<'tcx>, local: &LetStmt<'tcx>) -> Option<(HirId, &'tcx Expr<'tcx>)> {
+    if let PatKind::Binding(_, id, _, None) = local.pat.kind
+        && let Some(init) = local.init
+        && let ExprKind::Call(func, _) = init.kind
+        && let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind
+        && matches!(segment.ident.as_str(), "new" | "with_capacity" | "default")
+        && !local.span.from_expansion()
+        && let map_ty = cx.typeck_results().pat_ty(local.pat)
+        && is_type_diagnostic_item(cx, map_ty, sym::HashMap)
+        && let ty::Adt(_, args) = map_ty.kind()
+        && let Some(key_ty) = args.types().next()
+        && is_type_lang_item(cx, key_ty, LangItem::String)
+    {
+        Some((id, init))
+    } else {
+        None
+    }
+}
+
+/// Checks that every use of the map is a key-taking method call with a literal key, and that
+/// there is at least one `insert`.
+fn has_only_literal_keys(cx: &LateContext<'_>, local_id: HirId, init: &Expr<'_>) -> bool {
+    let mut has_insert = false;
+    let res = for_each_local_use_after_expr(cx, local_id, init.hir_id, |e| {
+        if let Some(parent) = get_parent_expr(cx, e)
+            && let ExprKind::MethodCall(segment, receiver, [key, ..], _) = parent.kind
+            && receiver.hir_id == e.hir_id
+            && matches!(segment.ident.as_str(), "insert" | "get" | "contains_key" | "remove")
+            && is_literal_key(cx, key)
+        {
+            has_insert |= segment.ident.as_str() == "insert";
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    });
+    res.is_continue() && has_insert
+}
+
+/// Matches a string literal, optionally converted to a `String` and borrowed.
+fn is_literal_key(cx: &LateContext<'_>, key: &Expr<'_>) -> bool {
+    let key = if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner) = key.kind {
+        inner
+    } else {
+        key
+    };
+    let lit = match key.kind {
+        ExprKind::Call(func, [arg])
+            if let ExprKind::Path(ref qpath) = func.kind
+                && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+                && cx.tcx.is_diagnostic_item(sym::from_fn, def_id) =>
+        {
+            arg
+        },
+        ExprKind::MethodCall(segment, receiver, [], _)
+            if matches!(segment.ident.as_str(), "to_string" | "to_owned" | "into") =>
+        {
+            receiver
+        },
+        _ => key,
+    };
+    if lit.hir_id != key.hir_id && !is_type_lang_item(cx, cx.typeck_results().expr_ty(key), LangItem::String) {
+        return false;
+    }
+    matches!(lit.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Str(..)))
+}
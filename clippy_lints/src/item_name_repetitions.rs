@@ -208,7 +208,7 @@ fn check_fields(cx: &LateContext<'_>, threshold: u64, item: &Item<'_>, fields: &
 
         pre = pre
             .into_iter()
-            .zip(field_split.iter())
+            .zip(&field_split)
             .take_while(|(a, b)| &a == b)
             .map(|e| e.0)
             .collect();
@@ -258,7 +258,7 @@ fn check_struct_name_repetition(cx: &LateContext<'_>, item: &Item<'_>, fields: &
             let field_words: Vec<&str> = field.ident.name.as_str().split('_').collect();
             if field_words.len() >= item_name_words.len() {
                 // if the field name is shorter than the struct name it cannot contain it
-                if field_words.iter().zip(item_name_words.iter()).all(|(a, b)| a == b) {
+                if field_words.iter().zip(&item_name_words).all(|(a, b)| a == b) {
                     span_lint_hir(
                         cx,
                         STRUCT_FIELD_NAMES,
@@ -349,7 +349,7 @@ fn check_variant(cx: &LateContext<'_>, threshold: u64, def: &EnumDef<'_>, item_n
 
         pre = pre
             .iter()
-            .zip(variant_split.iter())
+            .zip(&variant_split)
             .take_while(|(a, b)| a == b)
             .map(|e| *e.0)
             .collect();
@@ -2,7 +2,8 @@ use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::is_from_proc_macro;
 use clippy_utils::macros::macro_backtrace;
-use clippy_utils::source::snippet;
+use clippy_utils::source::snippet_with_applicability;
+use rustc_errors::Applicability;
 use rustc_hir::{ArrayLen, Expr, ExprKind, Item, ItemKind, Node};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::layout::LayoutOf;
@@ -17,10 +18,18 @@ declare_clippy_lint! {
     /// ### Why is this bad?
     /// Large local arrays may cause stack overflow.
     ///
+    /// ### Known problems
+    /// `Box::new([0u32; 1_000_000])` may still build the array on the stack before moving it to
+    /// the heap, unlike `vec![0u32; 1_000_000]`.
+    ///
     /// ### Example
     /// ```rust,ignore
     /// let a = [0u32; 1_000_000];
     /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let a = vec![0u32; 1_000_000];
+    /// ```
     #[clippy::version = "1.41.0"]
     pub LARGE_STACK_ARRAYS,
     pedantic,
@@ -35,7 +44,7 @@ pub struct LargeStackArrays {
 impl LargeStackArrays {
     pub fn new(conf: &'static Conf) -> Self {
         Self {
-            maximum_allowed_size: conf.array_size_threshold,
+            maximum_allowed_size: conf.max_stack_array_bytes.unwrap_or(conf.array_size_threshold),
             prev_vec_macro_callsite: None,
         }
     }
@@ -71,7 +80,7 @@ impl<'tcx> LateLintPass<'tcx> for LargeStackArrays {
                 matches!(
                     node,
                     Node::Item(Item {
-                        kind: ItemKind::Static(..),
+                        kind: ItemKind::Static(..) | ItemKind::Const(..),
                         ..
                     })
                 )
@@ -88,10 +97,14 @@ impl<'tcx> LateLintPass<'tcx> for LargeStackArrays {
                 ),
                 |diag| {
                     if !might_be_expanded(cx, expr) {
-                        diag.help(format!(
-                            "consider allocating on the heap with `vec!{}.into_boxed_slice()`",
-                            snippet(cx, expr.span, "[...]")
-                        ));
+                        let mut applicability = Applicability::MaybeIncorrect;
+                        let array = snippet_with_applicability(cx, expr.span, "[...]", &mut applicability);
+                        diag.span_suggestions(
+                            expr.span,
+                            "consider allocating on the heap",
+                            [format!("vec!{array}"), format!("Box::new({array})")],
+                            applicability,
+                        );
                     }
                 },
             );
@@ -1,12 +1,13 @@
-use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::ty::{implements_trait, is_must_use_ty, match_type};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::ty::{implements_trait, is_must_use_ty, is_type_diagnostic_item, match_type};
 use clippy_utils::{is_from_proc_macro, is_must_use_func_call, paths};
-use rustc_hir::{LetStmt, LocalSource, PatKind};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, LetStmt, LocalSource, Node, PatKind};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
-use rustc_middle::ty::{GenericArgKind, IsSuggestable};
+use rustc_middle::ty::{GenericArg, GenericArgKind, IsSuggestable, Ty};
 use rustc_session::declare_lint_pass;
-use rustc_span::{BytePos, Span};
+use rustc_span::{sym, BytePos, Span};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -128,7 +129,66 @@ declare_clippy_lint! {
     "non-binding `let` without a type annotation"
 }
 
-declare_lint_pass!(LetUnderscore => [LET_UNDERSCORE_MUST_USE, LET_UNDERSCORE_LOCK, LET_UNDERSCORE_FUTURE, LET_UNDERSCORE_UNTYPED]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `let _ = <expr>` where `<expr>` is a call to a method named `send` returning a
+    /// `Result`, such as `std::sync::mpsc::Sender::send`.
+    ///
+    /// ### Why restrict this?
+    /// Sending on a channel fails when the receiving end has been dropped. Ignoring the error lets
+    /// the program carry on as if the message was delivered, which is rarely intended.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let (sender, receiver) = std::sync::mpsc::channel();
+    /// let _ = sender.send(1);
+    /// # drop(receiver);
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let (sender, receiver) = std::sync::mpsc::channel();
+    /// sender.send(1).expect("receiver dropped");
+    /// # drop(receiver);
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CHANNEL_SEND_IGNORED,
+    restriction,
+    "non-binding `let` on the result of sending on a channel"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `let _ = <expr>` where the type of `<expr>` doesn't need to be dropped, i.e.
+    /// neither it nor any of its fields implement `Drop`, and which doesn't contain raw pointers.
+    ///
+    /// ### Why restrict this?
+    /// `let _ = <expr>` is usually written to drop the value at that point. When there is nothing
+    /// to drop, the `let _ =` only adds noise, and `<expr>;` does the same.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let mut v = vec![1, 2];
+    /// let _ = v.pop();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let mut v = vec![1, 2];
+    /// v.pop();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub LET_UNDERSCORE_NO_DROP,
+    restriction,
+    "non-binding `let` on a value which doesn't need to be dropped"
+}
+
+declare_lint_pass!(LetUnderscore => [
+    LET_UNDERSCORE_MUST_USE,
+    LET_UNDERSCORE_LOCK,
+    LET_UNDERSCORE_FUTURE,
+    LET_UNDERSCORE_UNTYPED,
+    CHANNEL_SEND_IGNORED,
+    LET_UNDERSCORE_NO_DROP,
+]);
 
 const SYNC_GUARD_PATHS: [&[&str]; 3] = [
     &paths::PARKING_LOT_MUTEX_GUARD,
@@ -137,6 +197,7 @@ const SYNC_GUARD_PATHS: [&[&str]; 3] = [
 ];
 
 impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
+    #[expect(clippy::too_many_lines)]
     fn check_local(&mut self, cx: &LateContext<'tcx>, local: &LetStmt<'tcx>) {
         if matches!(local.source, LocalSource::Normal)
             && let PatKind::Wild = local.pat.kind
@@ -187,6 +248,35 @@ impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
                     None,
                     "consider explicitly using function result",
                 );
+            } else {
+                check_no_drop(cx, local, init, init_ty);
+            }
+
+            if let ExprKind::MethodCall(method, ..) = init.kind
+                && method.ident.name.as_str() == "send"
+                && !init.span.from_expansion()
+                && is_type_diagnostic_item(cx, init_ty, sym::Result)
+            {
+                span_lint_and_then(
+                    cx,
+                    CHANNEL_SEND_IGNORED,
+                    local.span,
+                    "non-binding `let` on the result of a `send` call",
+                    |diag| {
+                        diag.multipart_suggestion(
+                            "consider panicking if the receiver is gone",
+                            vec![
+                                (local.span.until(init.span), String::new()),
+                                (init.span.shrink_to_hi(), ".expect(\"receiver dropped\")".to_owned()),
+                            ],
+                            Applicability::MaybeIncorrect,
+                        );
+                        diag.help(
+                            "alternatively, handle the error with a `match`, or use `.ok()` and explain \
+                                in a comment why the message may be dropped",
+                        );
+                    },
+                );
             }
 
             if local.pat.default_binding_modes && local.ty.is_none() {
@@ -221,3 +311,39 @@ impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
         }
     }
 }
+
+fn check_no_drop<'tcx>(cx: &LateContext<'tcx>, local: &LetStmt<'tcx>, init: &Expr<'tcx>, init_ty: Ty<'tcx>) {
+    if local.ty.is_none()
+        && !local.span.from_expansion()
+        && !init_ty.needs_drop(cx.tcx, cx.param_env)
+        && !init_ty.walk().filter_map(GenericArg::as_type).any(Ty::is_unsafe_ptr)
+        && !is_from_proc_macro(cx, init)
+    {
+        span_lint_and_then(
+            cx,
+            LET_UNDERSCORE_NO_DROP,
+            local.span,
+            "non-binding `let` on a value which doesn't need to be dropped",
+            |diag| {
+                // Without side effects, the expression statement would be useless as well
+                if !init.can_have_side_effects()
+                    && let Node::Stmt(stmt) = cx.tcx.parent_hir_node(local.hir_id)
+                {
+                    diag.span_suggestion(
+                        stmt.span,
+                        "consider removing the statement",
+                        "",
+                        Applicability::MaybeIncorrect,
+                    );
+                } else {
+                    diag.span_suggestion(
+                        local.span.until(init.span),
+                        "consider removing the `let _ =`",
+                        "",
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+            },
+        );
+    }
+}
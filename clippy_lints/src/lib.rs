@@ -91,6 +91,10 @@ mod bool_to_int_with_if;
 mod booleans;
 mod borrow_deref_ref;
 mod box_default;
+mod box_pin_unpin_type;
+mod box_tiny_type;
+mod boxed_error_in_lib_api;
+mod btreemap_where_hashmap_suffices;
 mod byte_char_slices;
 mod cargo;
 mod casts;
@@ -100,6 +104,7 @@ mod cognitive_complexity;
 mod collapsible_if;
 mod collection_is_never_read;
 mod comparison_chain;
+mod constant_if_condition;
 mod copies;
 mod copy_iterator;
 mod crate_in_macro_def;
@@ -154,6 +159,7 @@ mod from_raw_with_void_ptr;
 mod from_str_radix_10;
 mod functions;
 mod future_not_send;
+mod hashmap_string_literal_key;
 mod if_let_mutex;
 mod if_not_else;
 mod if_then_some_else_none;
@@ -220,6 +226,7 @@ mod manual_slice_size_calculation;
 mod manual_string_new;
 mod manual_strip;
 mod manual_unwrap_or_default;
+mod map_bool_as_set;
 mod map_unit_fn;
 mod match_result_ok;
 mod matches;
@@ -291,15 +298,18 @@ mod pathbuf_init_then_push;
 mod pattern_type_mismatch;
 mod permissions_set_readonly_false;
 mod precedence;
+mod prefer_once_lock;
 mod ptr;
 mod ptr_offset_with_cast;
 mod pub_underscore_fields;
 mod pub_use;
+mod push_str_to_format;
 mod question_mark;
 mod question_mark_used;
 mod ranges;
 mod raw_strings;
 mod rc_clone_in_vec_init;
+mod rc_refcell_undocumented;
 mod read_zero_byte_vec;
 mod redundant_async_block;
 mod redundant_clone;
@@ -327,15 +337,18 @@ mod serde_api;
 mod set_contains_or_insert;
 mod shadow;
 mod significant_drop_tightening;
+mod silent_result_discard;
 mod single_call_fn;
 mod single_char_lifetime_names;
 mod single_component_path_imports;
 mod single_range_in_vec_init;
 mod size_of_in_element_count;
 mod size_of_ref;
+mod sleep_zero_duration;
 mod slow_vector_initialization;
 mod std_instead_of_core;
 mod string_patterns;
+mod string_push_str_in_loop;
 mod strings;
 mod strlen_on_c_strings;
 mod suspicious_operation_groupings;
@@ -353,6 +366,7 @@ mod trait_bounds;
 mod transmute;
 mod tuple_array_conversions;
 mod types;
+mod uncfg_test_import;
 mod unconditional_recursion;
 mod undocumented_unsafe_blocks;
 mod unicode;
@@ -382,6 +396,7 @@ mod use_self;
 mod useless_conversion;
 mod vec;
 mod vec_init_then_push;
+mod vec_return_in_loop;
 mod visibility;
 mod whitelist_mutation;
 mod wildcard_imports;
@@ -911,7 +926,22 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(set_contains_or_insert::HashsetInsertAfterContains));
     store.register_early_pass(|| Box::new(byte_char_slices::ByteCharSlice));
     store.register_early_pass(|| Box::new(cfg_not_test::CfgNotTest));
-    store.register_late_pass(|_| Box::new(whitelist_mutation::FieldsMutatedByWhitelist::new()));
+    store.register_late_pass(move |_| Box::new(whitelist_mutation::FieldsMutatedByWhitelist::new(conf)));
+    store.register_late_pass(|_| Box::new(constant_if_condition::ConstantIfCondition));
+    store.register_late_pass(|_| Box::new(uncfg_test_import::UncfgTestImport));
+    store.register_late_pass(|_| Box::new(sleep_zero_duration::SleepZeroDuration));
+    store.register_late_pass(|_| Box::new(box_tiny_type::BoxTinyType));
+    store.register_late_pass(|_| Box::new(push_str_to_format::PushStrToFormat));
+    store.register_late_pass(|_| Box::new(hashmap_string_literal_key::HashMapStringLiteralKey));
+    store.register_late_pass(|_| Box::new(rc_refcell_undocumented::RcRefCellUndocumented));
+    store.register_late_pass(|_| Box::new(btreemap_where_hashmap_suffices::BTreeMapWhereHashMapSuffices));
+    store.register_late_pass(|_| Box::new(string_push_str_in_loop::StringPushStrInLoop));
+    store.register_late_pass(|_| Box::new(boxed_error_in_lib_api::BoxedErrorInLibApi));
+    store.register_late_pass(|_| Box::new(vec_return_in_loop::VecReturnInLoop));
+    store.register_late_pass(|_| Box::new(box_pin_unpin_type::BoxPinUnpinType));
+    store.register_late_pass(move |_| Box::new(prefer_once_lock::PreferOnceLock::new(conf)));
+    store.register_late_pass(|_| Box::<map_bool_as_set::MapBoolAsSet>::default());
+    store.register_late_pass(|_| Box::new(silent_result_discard::SilentResultDiscard));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
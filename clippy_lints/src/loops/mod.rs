@@ -349,6 +349,19 @@ declare_clippy_lint! {
     ///     break;
     /// }
     /// ```
+    ///
+    /// A loop consisting only of `break value` is a block evaluating to `value`:
+    /// ```no_run
+    /// # fn compute() -> u32 { 0 }
+    /// let x = loop {
+    ///     break compute();
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn compute() -> u32 { 0 }
+    /// let x = { compute() };
+    /// ```
     #[clippy::version = "pre 1.29.0"]
     pub NEVER_LOOP,
     correctness,
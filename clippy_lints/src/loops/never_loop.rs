@@ -3,12 +3,14 @@ use super::NEVER_LOOP;
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::higher::ForLoop;
 use clippy_utils::macros::root_macro_call_first_node;
-use clippy_utils::source::snippet;
+use clippy_utils::source::{snippet, snippet_with_applicability};
+use clippy_utils::visitors::for_each_expr_without_closures;
 use rustc_errors::Applicability;
 use rustc_hir::{Block, Destination, Expr, ExprKind, HirId, InlineAsmOperand, Pat, Stmt, StmtKind};
 use rustc_lint::LateContext;
 use rustc_span::{sym, Span};
 use std::iter::once;
+use std::ops::ControlFlow;
 
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
@@ -36,6 +38,24 @@ pub(super) fn check<'tcx>(
                         for_to_if_let_sugg(cx, iterator, pat),
                         Applicability::Unspecified,
                     );
+                } else if let Some(break_expr) = single_break(block) {
+                    if let ExprKind::Break(_, Some(value)) = break_expr.kind {
+                        let mut app = Applicability::MachineApplicable;
+                        let value = snippet_with_applicability(cx, value.span, "..", &mut app);
+                        diag.span_suggestion_verbose(
+                            span,
+                            "if the loop is only used to produce a value, use a block instead",
+                            format!("{{ {value} }}"),
+                            app,
+                        );
+                    } else {
+                        diag.span_suggestion_verbose(
+                            span,
+                            "the loop does nothing, use an empty block instead",
+                            "{}",
+                            Applicability::MachineApplicable,
+                        );
+                    }
                 }
             });
         },
@@ -43,6 +63,35 @@ pub(super) fn check<'tcx>(
     }
 }
 
+/// Returns the `break` if the loop body consists of nothing but an unlabeled `break`, with or
+/// without a value. The loop can then be replaced by a block evaluating to the value, or an empty
+/// block.
+fn single_break<'tcx>(block: &Block<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let break_expr = match (block.stmts, block.expr) {
+        ([], Some(expr)) => expr,
+        ([stmt], None) if let StmtKind::Semi(expr) = stmt.kind => expr,
+        _ => return None,
+    };
+    if let ExprKind::Break(Destination { label: None, .. }, value) = break_expr.kind
+        && !break_expr.span.from_expansion()
+        // Any `break` or `continue` in the value would refer to the loop
+        && value.map_or(true, |value| {
+            for_each_expr_without_closures(value, |e| {
+                if matches!(e.kind, ExprKind::Break(..) | ExprKind::Continue(_)) {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_none()
+        })
+    {
+        Some(break_expr)
+    } else {
+        None
+    }
+}
+
 /// The `never_loop` analysis keeps track of three things:
 ///
 /// * Has any (reachable) code path hit a `continue` of the main loop?
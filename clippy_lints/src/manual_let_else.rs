@@ -360,7 +360,7 @@ fn expr_simple_identity_map<'a, 'hir>(
         return None;
     }
     let mut ident_map = FxHashMap::default();
-    for (sub_pat, path) in sub_pats.iter().zip(paths.iter()) {
+    for (sub_pat, path) in sub_pats.iter().zip(paths) {
         if let ExprKind::Path(QPath::Resolved(_ty, path)) = path.kind
             && let [path_seg] = path.segments
         {
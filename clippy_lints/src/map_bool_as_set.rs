@@ -0,0 +1,277 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_hir_and_then};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{get_parent_expr, last_path_segment};
+use core::ops::ControlFlow;
+use rustc_ast::LitKind;
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::pat_util::EnumerateAndAdjustIterator;
+use rustc_hir::{Expr, ExprKind, FieldDef, HirId, LetStmt, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::print::with_forced_trimmed_paths;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, Span};
+use rustc_target::abi::FieldIdx;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `HashMap<_, bool>` and `BTreeMap<_, bool>` local bindings and private struct
+    /// fields where every `insert` call inserts `true`.
+    ///
+    /// ### Why is this bad?
+    /// A map whose values are always `true` is a set: the presence of a key is the only
+    /// information it holds. `HashSet` and `BTreeSet` make this explicit, and don't store the
+    /// values.
+    ///
+    /// ### Known problems
+    /// The lint only fires when the map isn't used in any other way than through methods which
+    /// don't depend on the values, such as `contains_key` and `remove`, since other uses might
+    /// read or change them. Maps returned from a function aren't linted, as the callers may insert
+    /// `false` or read the values.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// let mut seen = HashMap::new();
+    /// seen.insert("a", true);
+    /// assert!(seen.contains_key("a"));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::collections::HashSet;
+    /// let mut seen = HashSet::new();
+    /// seen.insert("a");
+    /// assert!(seen.contains("a"));
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MAP_BOOL_AS_SET,
+    pedantic,
+    "`HashMap<_, bool>` or `BTreeMap<_, bool>` only ever containing `true`, where a set would do"
+}
+
+/// Struct fields are checked across all bodies of the crate: their uses are recorded as the
+/// bodies are visited, and the fields are reported in `check_crate_post`.
+#[derive(Default)]
+pub struct MapBoolAsSet {
+    /// The private struct fields of map type with `bool` values, along with the span of their
+    /// type and the suggested set type.
+    fields: FxIndexMap<LocalDefId, (Span, String)>,
+    /// The fields with at least one `insert` of `true`.
+    inserted_fields: FxHashSet<LocalDefId>,
+    /// The fields used in any other way than as a set.
+    disqualified_fields: FxHashSet<LocalDefId>,
+}
+
+impl_lint_pass!(MapBoolAsSet => [MAP_BOOL_AS_SET]);
+
+/// The methods of maps which don't depend on the values, besides `insert`.
+const SET_METHODS: &[&str] = &[
+    "contains_key",
+    "remove",
+    "len",
+    "is_empty",
+    "clear",
+    "keys",
+    "into_keys",
+];
+
+impl<'tcx> LateLintPass<'tcx> for MapBoolAsSet {
+    fn check_field_def(&mut self, cx: &LateContext<'tcx>, field: &'tcx FieldDef<'tcx>) {
+        if cx.tcx.def_kind(cx.tcx.local_parent(field.def_id)) == DefKind::Struct
+            && !field.span.from_expansion()
+            && !cx.effective_visibilities.is_exported(field.def_id)
+            && let Some(set) = set_type(cx, cx.tcx.type_of(field.def_id).instantiate_identity())
+        {
+            self.fields.insert(field.def_id, (field.ty.span, set));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        match expr.kind {
+            ExprKind::Field(..) => {
+                if let Some(field) = accessed_field(cx, expr)
+                    && set_type(cx, cx.typeck_results().expr_ty(expr)).is_some()
+                {
+                    match set_use(cx, expr) {
+                        Some(true) => {
+                            self.inserted_fields.insert(field);
+                        },
+                        Some(false) => {},
+                        None => {
+                            self.disqualified_fields.insert(field);
+                        },
+                    }
+                }
+            },
+            // A field initialized with anything but an empty map may contain `false` values
+            ExprKind::Struct(_, fields, _) => {
+                if let ty::Adt(adt, _) = cx.typeck_results().expr_ty(expr).kind()
+                    && adt.is_struct()
+                {
+                    for field in fields {
+                        if let Some(field_idx) = cx.typeck_results().opt_field_index(field.hir_id)
+                            && let Some(field_def_id) = adt.non_enum_variant().fields[field_idx].did.as_local()
+                            && !is_empty_map(field.expr)
+                        {
+                            self.disqualified_fields.insert(field_def_id);
+                        }
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    // A struct pattern binds the fields without going through a field expression
+    fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'tcx>) {
+        let ty::Adt(adt, _) = cx.typeck_results().pat_ty(pat).peel_refs().kind() else {
+            return;
+        };
+        if !adt.is_struct() {
+            return;
+        }
+        let fields = &adt.non_enum_variant().fields;
+        match pat.kind {
+            PatKind::Struct(_, pat_fields, _) => {
+                for pat_field in pat_fields {
+                    if let Some(field_idx) = cx.typeck_results().opt_field_index(pat_field.hir_id)
+                        && let Some(field) = fields[field_idx].did.as_local()
+                    {
+                        self.disqualified_fields.insert(field);
+                    }
+                }
+            },
+            PatKind::TupleStruct(_, pats, dotdot) => {
+                for (field_idx, _) in pats.iter().enumerate_and_adjust(fields.len(), dotdot) {
+                    if let Some(field) = fields[FieldIdx::from_usize(field_idx)].did.as_local() {
+                        self.disqualified_fields.insert(field);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx LetStmt<'tcx>) {
+        if let PatKind::Binding(_, local_id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+            && is_empty_map(init)
+            && !local.span.from_expansion()
+            && let Some(set) = set_type(cx, cx.typeck_results().pat_ty(local.pat))
+            && is_local_set_use(cx, local_id, init)
+        {
+            span_lint_and_help(
+                cx,
+                MAP_BOOL_AS_SET,
+                local.span,
+                "this map only ever contains `true` values",
+                None,
+                format!("consider using a `{set}`"),
+            );
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (field, (ty_span, set)) in std::mem::take(&mut self.fields) {
+            if self.inserted_fields.contains(&field) && !self.disqualified_fields.contains(&field) {
+                span_lint_hir_and_then(
+                    cx,
+                    MAP_BOOL_AS_SET,
+                    cx.tcx.local_def_id_to_hir_id(field),
+                    ty_span,
+                    "this map only ever contains `true` values",
+                    |diag| {
+                        diag.help(format!("consider using a `{set}`"));
+                    },
+                );
+            }
+        }
+        self.inserted_fields.clear();
+        self.disqualified_fields.clear();
+    }
+}
+
+/// Returns the set type to use instead, if the type is a `HashMap` or `BTreeMap` with `bool`
+/// values.
+fn set_type<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<String> {
+    let set = if is_type_diagnostic_item(cx, ty, sym::HashMap) {
+        "HashSet"
+    } else if is_type_diagnostic_item(cx, ty, sym::BTreeMap) {
+        "BTreeSet"
+    } else {
+        return None;
+    };
+    if let ty::Adt(_, args) = ty.kind()
+        && let [key_ty, value_ty] = args.types().take(2).collect::<Vec<_>>()[..]
+        && value_ty.is_bool()
+    {
+        Some(with_forced_trimmed_paths!(format!("{set}<{key_ty}>")))
+    } else {
+        None
+    }
+}
+
+/// Matches a call to `new`, `default` or `with_capacity`.
+fn is_empty_map(expr: &Expr<'_>) -> bool {
+    if let ExprKind::Call(func, _) = expr.kind
+        && let ExprKind::Path(ref qpath) = func.kind
+    {
+        matches!(
+            last_path_segment(qpath).ident.as_str(),
+            "new" | "default" | "with_capacity"
+        )
+    } else {
+        false
+    }
+}
+
+fn accessed_field(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<LocalDefId> {
+    if let ExprKind::Field(base, _) = expr.kind
+        && let Some(field_idx) = cx.typeck_results().opt_field_index(expr.hir_id)
+        && let ty::Adt(adt, _) = cx.typeck_results().expr_ty_adjusted(base).peel_refs().kind()
+        && adt.is_struct()
+    {
+        adt.non_enum_variant().fields[field_idx].did.as_local()
+    } else {
+        None
+    }
+}
+
+/// Checks whether the map is used as a set, i.e. as the receiver of a method which doesn't depend
+/// on the values, or of an `insert` of `true`. Returns whether it's an `insert`.
+fn set_use(cx: &LateContext<'_>, map: &Expr<'_>) -> Option<bool> {
+    let parent = get_parent_expr(cx, map)?;
+    if let ExprKind::MethodCall(segment, receiver, args, _) = parent.kind
+        && receiver.hir_id == map.hir_id
+    {
+        match (segment.ident.as_str(), args) {
+            ("insert", [_, value]) => (!value.span.from_expansion()
+                && matches!(value.kind, ExprKind::Lit(lit) if lit.node == LitKind::Bool(true)))
+            .then_some(true),
+            (method, _) if SET_METHODS.contains(&method) => Some(false),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Checks that the binding is only used as a set after its initializer, with at least one
+/// `insert`.
+fn is_local_set_use(cx: &LateContext<'_>, local_id: HirId, init: &Expr<'_>) -> bool {
+    let mut has_insert = false;
+    let res = for_each_local_use_after_expr(cx, local_id, init.hir_id, |e| match set_use(cx, e) {
+        Some(is_insert) => {
+            has_insert |= is_insert;
+            ControlFlow::Continue(())
+        },
+        None => ControlFlow::Break(()),
+    });
+    res.is_continue() && has_insert
+}
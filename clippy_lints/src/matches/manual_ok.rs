@@ -0,0 +1,126 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::contains_break_or_continue;
+use clippy_utils::{contains_return, is_res_lang_ctor, path_res, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome, ResultErr, ResultOk};
+use rustc_hir::{Arm, BindingMode, Expr, ExprKind, LangItem, Pat, PatKind, QPath};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::MANUAL_OK;
+
+pub(super) fn check_match<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'_>],
+) {
+    let [first, second] = arms else {
+        return;
+    };
+    if first.guard.is_some() || second.guard.is_some() {
+        return;
+    }
+    let ty = cx.typeck_results().expr_ty(scrutinee);
+
+    for (value_arm, other_arm) in [(first, second), (second, first)] {
+        let (method, arg) = if is_type_diagnostic_item(cx, ty, sym::Result) {
+            if is_wrapped_binding(cx, value_arm, ResultOk, OptionSome) && is_empty_arm(cx, other_arm, ResultErr) {
+                ("ok", None)
+            } else if is_wrapped_binding(cx, value_arm, ResultErr, OptionSome) && is_empty_arm(cx, other_arm, ResultOk)
+            {
+                ("err", None)
+            } else {
+                continue;
+            }
+        } else if is_type_diagnostic_item(cx, ty, sym::Option)
+            && is_wrapped_binding(cx, value_arm, OptionSome, ResultOk)
+            && is_path_pat(cx, other_arm.pat, OptionNone)
+            && let ExprKind::Call(ctor, [err]) = peel_blocks(other_arm.body).kind
+            && is_res_lang_ctor(cx, path_res(cx, ctor), ResultErr)
+            && !contains_return(err)
+            && !contains_break_or_continue(err)
+            && !is_coerced(cx, err)
+        {
+            if let ExprKind::Call(func, []) = err.kind
+                && let ExprKind::Path(QPath::Resolved(None, _)) = func.kind
+            {
+                ("ok_or_else", Some((func, "")))
+            } else if switch_to_eager_eval(cx, err) {
+                ("ok_or", Some((err, "")))
+            } else {
+                ("ok_or_else", Some((err, "|| ")))
+            }
+        } else {
+            continue;
+        };
+
+        let mut applicability = Applicability::MachineApplicable;
+        let recv = Sugg::hir_with_context(cx, scrutinee, expr.span.ctxt(), "..", &mut applicability).maybe_par();
+        let arg = arg.map_or(String::new(), |(arg, prefix)| {
+            let (snip, _) = snippet_with_context(cx, arg.span, expr.span.ctxt(), "..", &mut applicability);
+            format!("{prefix}{snip}")
+        });
+        let type_name = if method.starts_with("ok_or") {
+            "Option"
+        } else {
+            "Result"
+        };
+        span_lint_and_sugg(
+            cx,
+            MANUAL_OK,
+            expr.span,
+            format!("manual implementation of `{type_name}::{method}`"),
+            "try",
+            format!("{recv}.{method}({arg})"),
+            applicability,
+        );
+        return;
+    }
+}
+
+/// Checks for an arm matching `Variant(x)` whose body is `Wrapper(x)`, e.g. `Ok(x) => Some(x)`.
+fn is_wrapped_binding(cx: &LateContext<'_>, arm: &Arm<'_>, variant: LangItem, wrapper: LangItem) -> bool {
+    if let PatKind::TupleStruct(ref qpath, [inner], _) = arm.pat.kind
+        && is_res_lang_ctor(cx, cx.qpath_res(qpath, arm.pat.hir_id), variant)
+        && let PatKind::Binding(BindingMode::NONE, binding_id, _, None) = inner.kind
+        && let ExprKind::Call(ctor, [value]) = peel_blocks(arm.body).kind
+        && is_res_lang_ctor(cx, path_res(cx, ctor), wrapper)
+        && path_to_local_id(value, binding_id)
+        && !is_coerced(cx, value)
+    {
+        true
+    } else {
+        false
+    }
+}
+
+/// Checks for an arm matching `_` or `Variant(_)` whose body is `None`.
+fn is_empty_arm(cx: &LateContext<'_>, arm: &Arm<'_>, variant: LangItem) -> bool {
+    let matches_variant = match arm.pat.kind {
+        PatKind::Wild => true,
+        PatKind::TupleStruct(ref qpath, [inner], _) => {
+            matches!(inner.kind, PatKind::Wild) && is_res_lang_ctor(cx, cx.qpath_res(qpath, arm.pat.hir_id), variant)
+        },
+        _ => false,
+    };
+    matches_variant && is_res_lang_ctor(cx, path_res(cx, peel_blocks(arm.body)), OptionNone)
+}
+
+/// Whether the type of the expression is changed by a coercion, which wouldn't apply to the
+/// argument of the generic method.
+fn is_coerced(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results().expr_ty(expr) != cx.typeck_results().expr_ty_adjusted(expr)
+}
+
+fn is_path_pat(cx: &LateContext<'_>, pat: &Pat<'_>, variant: LangItem) -> bool {
+    match pat.kind {
+        PatKind::Wild => true,
+        PatKind::Path(ref qpath) => is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), variant),
+        _ => false,
+    }
+}
@@ -2,8 +2,12 @@ use super::REDUNDANT_PATTERN_MATCHING;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::{is_lint_allowed, is_wild, span_contains_comment};
+use itertools::Itertools as _;
 use rustc_ast::{Attribute, LitKind};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
+use rustc_hir::def::{CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
 use rustc_hir::{Arm, BorrowKind, Expr, ExprKind, Pat, PatKind, QPath};
 use rustc_lint::{LateContext, LintContext};
 use rustc_middle::ty;
@@ -45,7 +49,77 @@ pub(super) fn check_match<'tcx>(
             .map(|arm| (cx.tcx.hir().attrs(arm.hir_id), Some(arm.pat), arm.body, arm.guard)),
         e,
         false,
-    )
+    ) || find_variant_matches_sugg(cx, e, scrutinee, arms)
+}
+
+/// Lint a `match` whose arms return `true` and `false` in any order, e.g.
+/// `match x { A => true, B => false, C => true, D => false }`, as long as the arms can be
+/// reordered: each pattern matches distinct enum variants, except for an optional final wildcard.
+/// The arms of the bool with fewer arms are listed in the `matches!`, or if there is a wildcard,
+/// the arms of the other bool.
+fn find_variant_matches_sugg<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    scrutinee: &'tcx Expr<'_>,
+    arms: &'tcx [Arm<'tcx>],
+) -> bool {
+    let Some(bools) = arms
+        .iter()
+        .map(|arm| {
+            (arm.guard.is_none() && cx.tcx.hir().attrs(arm.hir_id).is_empty())
+                .then(|| find_bool_lit(&arm.body.kind))
+                .flatten()
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return false;
+    };
+    let (variant_arms, wild_bool) = match arms {
+        [variant_arms @ .., last] if is_wild(last.pat) => (variant_arms, Some(bools[arms.len() - 1])),
+        _ => (arms, None),
+    };
+    let mut variants = FxHashSet::default();
+    if span_contains_comment(cx.sess().source_map(), expr.span)
+        || !cx.typeck_results().expr_ty(expr).is_bool()
+        || !bools.contains(&true)
+        || !bools.contains(&false)
+        // `Some(_) => true, None => false` is linted by `redundant_pattern_matching`
+        || (arms.len() == 2 && !is_lint_allowed(cx, REDUNDANT_PATTERN_MATCHING, expr.hir_id))
+        || !variant_arms
+            .iter()
+            .all(|arm| pat_variants(cx, arm.pat, &mut |variant| variants.insert(variant)))
+    {
+        return false;
+    }
+
+    let true_count = bools.iter().filter(|&&b| b).count();
+    let listed = wild_bool.map_or(true_count * 2 <= arms.len(), |wild_bool| !wild_bool);
+    let mut applicability = Applicability::MachineApplicable;
+    let pat = variant_arms
+        .iter()
+        .zip(&bools)
+        .filter(|&(_, &b)| b == listed)
+        .map(|(arm, _)| snippet_with_applicability(cx, arm.pat.span, "..", &mut applicability))
+        .join(" | ");
+    lint_matches_sugg(cx, expr, scrutinee, &pat, listed, false, applicability);
+    true
+}
+
+/// Calls `f` with each enum variant matched by the pattern, returning `false` if the pattern is
+/// not a variant with only wildcard subpatterns, an or-pattern of those, or if `f` returns `false`.
+fn pat_variants(cx: &LateContext<'_>, pat: &Pat<'_>, f: &mut impl FnMut(DefId) -> bool) -> bool {
+    let qpath = match pat.kind {
+        PatKind::Or(pats) => return pats.iter().all(|pat| pat_variants(cx, pat, f)),
+        PatKind::Path(ref qpath) => qpath,
+        PatKind::TupleStruct(ref qpath, pats, _) if pats.iter().all(|pat| is_wild(pat)) => qpath,
+        PatKind::Struct(ref qpath, fields, _) if fields.iter().all(|field| is_wild(field.pat)) => qpath,
+        _ => return false,
+    };
+    match cx.qpath_res(qpath, pat.hir_id) {
+        Res::Def(DefKind::Ctor(CtorOf::Variant, _), ctor_id) => f(cx.tcx.parent(ctor_id)),
+        Res::Def(DefKind::Variant, variant_id) => f(variant_id),
+        _ => false,
+    }
 }
 
 /// Lint a `match` or `if let` for replacement by `matches!`
@@ -93,15 +167,12 @@ where
         // The suggestion may be incorrect, because some arms can have `cfg` attributes
         // evaluated into `false` and so such arms will be stripped before.
         let mut applicability = Applicability::MaybeIncorrect;
-        let pat = {
-            use itertools::Itertools as _;
-            iter_without_last
-                .filter_map(|arm| {
-                    let pat_span = arm.1?.span;
-                    Some(snippet_with_applicability(cx, pat_span, "..", &mut applicability))
-                })
-                .join(" | ")
-        };
+        let pat = iter_without_last
+            .filter_map(|arm| {
+                let pat_span = arm.1?.span;
+                Some(snippet_with_applicability(cx, pat_span, "..", &mut applicability))
+            })
+            .join(" | ");
         let pat_and_guard = if let Some(g) = first_guard {
             format!(
                 "{pat} if {}",
@@ -111,35 +182,47 @@ where
             pat
         };
 
-        // strip potential borrows (#6503), but only if the type is a reference
-        let mut ex_new = ex;
-        if let ExprKind::AddrOf(BorrowKind::Ref, .., ex_inner) = ex.kind {
-            if let ty::Ref(..) = cx.typeck_results().expr_ty(ex_inner).kind() {
-                ex_new = ex_inner;
-            }
-        };
-        span_lint_and_sugg(
-            cx,
-            MATCH_LIKE_MATCHES_MACRO,
-            expr.span,
-            format!(
-                "{} expression looks like `matches!` macro",
-                if is_if_let { "if let .. else" } else { "match" }
-            ),
-            "try",
-            format!(
-                "{}matches!({}, {pat_and_guard})",
-                if b0 { "" } else { "!" },
-                snippet_with_applicability(cx, ex_new.span, "..", &mut applicability),
-            ),
-            applicability,
-        );
+        lint_matches_sugg(cx, expr, ex, &pat_and_guard, b0, is_if_let, applicability);
         true
     } else {
         false
     }
 }
 
+fn lint_matches_sugg(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    ex: &Expr<'_>,
+    pat_and_guard: &str,
+    matched: bool,
+    is_if_let: bool,
+    mut applicability: Applicability,
+) {
+    // strip potential borrows (#6503), but only if the type is a reference
+    let mut ex_new = ex;
+    if let ExprKind::AddrOf(BorrowKind::Ref, .., ex_inner) = ex.kind {
+        if let ty::Ref(..) = cx.typeck_results().expr_ty(ex_inner).kind() {
+            ex_new = ex_inner;
+        }
+    };
+    span_lint_and_sugg(
+        cx,
+        MATCH_LIKE_MATCHES_MACRO,
+        expr.span,
+        format!(
+            "{} expression looks like `matches!` macro",
+            if is_if_let { "if let .. else" } else { "match" }
+        ),
+        "try",
+        format!(
+            "{}matches!({}, {pat_and_guard})",
+            if matched { "" } else { "!" },
+            snippet_with_applicability(cx, ex_new.span, "..", &mut applicability),
+        ),
+        applicability,
+    );
+}
+
 /// Extract a `bool` or `{ bool }`
 fn find_bool_lit(ex: &ExprKind<'_>) -> Option<bool> {
     match ex {
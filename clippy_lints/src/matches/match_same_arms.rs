@@ -383,7 +383,7 @@ impl<'a> NormalizedPat<'a> {
                 }
                 lpats
                     .iter()
-                    .zip(rpats.iter())
+                    .zip(rpats)
                     .all(|(lpat, rpat)| lpat.has_overlapping_values(rpat))
             },
             (Self::Path(x), Self::Path(y)) => x == y,
@@ -394,7 +394,7 @@ impl<'a> NormalizedPat<'a> {
             (Self::Range(ref x), Self::Range(ref y)) => x.overlaps(y),
             (Self::Range(ref range), Self::LitInt(x)) | (Self::LitInt(x), Self::Range(ref range)) => range.contains(x),
             (Self::Slice(lpats, None), Self::Slice(rpats, None)) => {
-                lpats.len() == rpats.len() && lpats.iter().zip(rpats.iter()).all(|(x, y)| x.has_overlapping_values(y))
+                lpats.len() == rpats.len() && lpats.iter().zip(rpats).all(|(x, y)| x.has_overlapping_values(y))
             },
             (Self::Slice(pats, None), Self::Slice(front, Some(back)))
             | (Self::Slice(front, Some(back)), Self::Slice(pats, None)) => {
@@ -405,13 +405,13 @@ impl<'a> NormalizedPat<'a> {
                 }
                 pats[..front.len()]
                     .iter()
-                    .zip(front.iter())
-                    .chain(pats[pats.len() - back.len()..].iter().zip(back.iter()))
+                    .zip(front)
+                    .chain(pats[pats.len() - back.len()..].iter().zip(back))
                     .all(|(x, y)| x.has_overlapping_values(y))
             },
             (Self::Slice(lfront, Some(lback)), Self::Slice(rfront, Some(rback))) => lfront
                 .iter()
-                .zip(rfront.iter())
+                .zip(rfront)
                 .chain(lback.iter().rev().zip(rback.iter().rev()))
                 .all(|(x, y)| x.has_overlapping_values(y)),
 
@@ -0,0 +1,25 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use rustc_errors::Applicability;
+use rustc_hir::{Arm, Expr};
+use rustc_lint::LateContext;
+
+use super::MATCH_UNIT_TYPE;
+
+/// Single-arm matches on `()` are linted by `match_single_binding`, so only matches on `!` without
+/// arms are checked here.
+pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, ex: &Expr<'tcx>, arms: &[Arm<'_>], expr: &Expr<'tcx>) {
+    if arms.is_empty() && !expr.span.from_expansion() && cx.typeck_results().expr_ty(ex).is_never() {
+        let mut app = Applicability::MachineApplicable;
+        let scrutinee = snippet_with_context(cx, ex.span, expr.span.ctxt(), "..", &mut app).0;
+        span_lint_and_sugg(
+            cx,
+            MATCH_UNIT_TYPE,
+            expr.span,
+            "this match on `!` could be replaced by its scrutinee",
+            "consider using the scrutinee instead",
+            scrutinee.into_owned(),
+            app,
+        );
+    }
+}
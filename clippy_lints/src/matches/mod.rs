@@ -2,6 +2,7 @@ mod collapsible_match;
 mod infallible_destructuring_match;
 mod manual_filter;
 mod manual_map;
+mod manual_ok;
 mod manual_unwrap_or;
 mod manual_utils;
 mod match_as_ref;
@@ -12,6 +13,7 @@ mod match_ref_pats;
 mod match_same_arms;
 mod match_single_binding;
 mod match_str_case_mismatch;
+mod match_unit_type;
 mod match_wild_enum;
 mod match_wild_err_arm;
 mod needless_match;
@@ -535,6 +537,9 @@ declare_clippy_lint! {
     /// Checks for `match`  or `if let` expressions producing a
     /// `bool` that could be written using `matches!`
     ///
+    /// The arms of a `match` returning `true` and `false` may come in any order if each of them
+    /// matches distinct enum variants, in which case the `bool` with fewer arms is matched.
+    ///
     /// ### Why is this bad?
     /// Readability and needless complexity.
     ///
@@ -975,6 +980,67 @@ declare_clippy_lint! {
     "checks for unnecessary guards in match expressions"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match` expressions converting between `Result` and `Option`, which can be
+    /// written with `Result::ok`, `Result::err`, `Option::ok_or` or `Option::ok_or_else`.
+    ///
+    /// ### Why is this bad?
+    /// The methods are shorter and state the intent directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let res: Result<u32, ()> = Ok(1);
+    /// # let opt = Some(1);
+    /// let _ = match res {
+    ///     Ok(v) => Some(v),
+    ///     Err(_) => None,
+    /// };
+    /// let _: Result<u32, &str> = match opt {
+    ///     Some(v) => Ok(v),
+    ///     None => Err("missing"),
+    /// };
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let res: Result<u32, ()> = Ok(1);
+    /// # let opt = Some(1);
+    /// let _ = res.ok();
+    /// let _: Result<u32, &str> = opt.ok_or("missing");
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MANUAL_OK,
+    style,
+    "`match` expressions which can be written with `ok`, `err`, `ok_or` or `ok_or_else`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `match` expressions without arms on a scrutinee of type `!`. Single-arm
+    /// `match` expressions on `()` are linted by `match_single_binding`.
+    ///
+    /// ### Why is this bad?
+    /// A `match` on `!` can't be reached, since the scrutinee never produces a value. The `match`
+    /// only hides that the expression is just its scrutinee.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn exit() -> u32 {
+    ///     match std::process::exit(1) {}
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn exit() -> u32 {
+    ///     std::process::exit(1)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MATCH_UNIT_TYPE,
+    style,
+    "`match` on `!` without arms"
+}
+
 pub struct Matches {
     msrv: Msrv,
     infallible_destructuring_match_linted: bool,
@@ -1016,6 +1082,8 @@ impl_lint_pass!(Matches => [
     MANUAL_MAP,
     MANUAL_FILTER,
     REDUNDANT_GUARDS,
+    MANUAL_OK,
+    MATCH_UNIT_TYPE,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
@@ -1073,6 +1141,7 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
                         manual_unwrap_or::check_match(cx, expr, ex, arms);
                         manual_map::check_match(cx, expr, ex, arms);
                         manual_filter::check_match(cx, ex, arms, expr);
+                        manual_ok::check_match(cx, expr, ex, arms);
                     }
 
                     if self.infallible_destructuring_match_linted {
@@ -1080,6 +1149,7 @@ impl<'tcx> LateLintPass<'tcx> for Matches {
                     } else {
                         match_single_binding::check(cx, ex, arms, expr);
                     }
+                    match_unit_type::check(cx, ex, arms, expr);
                 }
                 match_ref_pats::check(cx, ex, arms.iter().map(|el| el.pat), expr);
             }
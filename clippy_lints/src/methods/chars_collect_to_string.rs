@@ -0,0 +1,41 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_lang_item;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, LangItem};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+
+use super::CHARS_COLLECT_TO_STRING;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>) {
+    if !is_type_lang_item(cx, cx.typeck_results().expr_ty(expr), LangItem::String) || expr.span.from_expansion() {
+        return;
+    }
+    // Through more than one reference, `to_owned` and `clone` would copy a reference instead
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let recv_ty = if let ty::Ref(_, inner, _) = recv_ty.kind() {
+        *inner
+    } else {
+        recv_ty
+    };
+    let method = if recv_ty.is_str() {
+        "to_owned"
+    } else if is_type_lang_item(cx, recv_ty, LangItem::String) {
+        "clone"
+    } else {
+        return;
+    };
+
+    let mut applicability = Applicability::MachineApplicable;
+    let (recv_snip, _) = snippet_with_context(cx, recv.span, expr.span.ctxt(), "..", &mut applicability);
+    span_lint_and_sugg(
+        cx,
+        CHARS_COLLECT_TO_STRING,
+        expr.span,
+        "collecting the characters of a string into a `String`",
+        format!("use `{method}` instead"),
+        format!("{recv_snip}.{method}()"),
+        applicability,
+    );
+}
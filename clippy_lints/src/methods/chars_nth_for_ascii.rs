@@ -0,0 +1,77 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use rustc_ast::ExprPrecedence;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem, Node};
+use rustc_lint::LateContext;
+
+use crate::methods::method_call;
+
+use super::CHARS_NTH_FOR_ASCII;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, recv: &'tcx Expr<'tcx>, n_arg: &'tcx Expr<'tcx>) {
+    let ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    let caller_type = if ty.is_str() {
+        "str"
+    } else if is_type_lang_item(cx, ty, LangItem::String) {
+        "String"
+    } else {
+        return;
+    };
+    if expr.span.from_expansion() {
+        return;
+    }
+
+    let ctxt = expr.span.ctxt();
+    let mut applicability = Applicability::MaybeIncorrect;
+    let receiver = Sugg::hir_with_context(cx, recv, ctxt, "..", &mut applicability).maybe_par();
+    let (n, _) = snippet_with_context(cx, n_arg.span, ctxt, "..", &mut applicability);
+
+    let (replaced, msg, sugg) = if let Some(parent) = clippy_utils::get_parent_expr(cx, expr)
+        && let Some(("unwrap", _, [], _, _)) = method_call(parent)
+    {
+        let sugg = Sugg::NonParen(format!("{receiver}.as_bytes()[{n}]").into()).as_ty("char");
+        (
+            parent,
+            format!("called `.chars().nth().unwrap()` on a `{caller_type}`"),
+            if needs_cast_parens(cx, parent) {
+                sugg.maybe_par()
+            } else {
+                sugg
+            },
+        )
+    } else {
+        (
+            expr,
+            format!("called `.chars().nth()` on a `{caller_type}`"),
+            Sugg::NonParen(format!("{receiver}.as_bytes().get({n}).map(|&b| b as char)").into()),
+        )
+    };
+    span_lint_and_then(cx, CHARS_NTH_FOR_ASCII, replaced.span, msg, |diag| {
+        diag.span_suggestion(
+            replaced.span,
+            "if the string is ASCII-only, index its bytes",
+            sugg,
+            applicability,
+        );
+        diag.note("this gives a different result if the string contains any non-ASCII character");
+    });
+}
+
+/// Checks whether a cast replacing the expression needs parentheses, i.e. whether the parent binds
+/// tighter than a cast, or is a `<` or `<<` which would be parsed as the start of generic
+/// arguments.
+fn needs_cast_parens(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let Node::Expr(parent) = cx.tcx.parent_hir_node(expr.hir_id) {
+        match parent.kind {
+            ExprKind::Binary(op, lhs, _) if matches!(op.node, BinOpKind::Lt | BinOpKind::Shl) => {
+                lhs.hir_id == expr.hir_id
+            },
+            _ => parent.precedence().order() > ExprPrecedence::Cast.order(),
+        }
+    } else {
+        false
+    }
+}
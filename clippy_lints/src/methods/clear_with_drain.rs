@@ -1,8 +1,8 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::is_range_full;
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::{is_path_diagnostic_item, is_range_full};
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, LangItem, QPath};
+use rustc_hir::{Expr, ExprKind, LangItem, Node, PatKind, QPath, Stmt, StmtKind};
 use rustc_lint::LateContext;
 use rustc_span::symbol::sym;
 use rustc_span::Span;
@@ -14,19 +14,69 @@ const ACCEPTABLE_TYPES_WITH_ARG: [rustc_span::Symbol; 2] = [sym::Vec, sym::VecDe
 
 const ACCEPTABLE_TYPES_WITHOUT_ARG: [rustc_span::Symbol; 3] = [sym::BinaryHeap, sym::HashMap, sym::HashSet];
 
-pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, span: Span, arg: Option<&Expr<'_>>) {
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    recv: &Expr<'_>,
+    span: Span,
+    arg: Option<&Expr<'_>>,
+    removed_spans: Vec<Span>,
+) {
     if let Some(arg) = arg {
         if match_acceptable_type(cx, recv, &ACCEPTABLE_TYPES_WITH_ARG)
             && let ExprKind::Path(QPath::Resolved(None, container_path)) = recv.kind
             && is_range_full(cx, arg, Some(container_path))
         {
-            suggest(cx, expr, recv, span);
+            suggest(cx, expr, recv, span, removed_spans);
         }
     } else if match_acceptable_type(cx, recv, &ACCEPTABLE_TYPES_WITHOUT_ARG) {
-        suggest(cx, expr, recv, span);
+        suggest(cx, expr, recv, span, removed_spans);
     }
 }
 
+/// Checks if the iterator returned by the `drain` call `expr` is discarded right away. If so,
+/// returns the spans of the surrounding code to remove along with replacing `drain` by `clear`.
+pub(super) fn discarded_drain(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Vec<Span>> {
+    match cx.tcx.parent_hir_node(expr.hir_id) {
+        Node::Stmt(stmt) if is_semi_stmt(stmt) => Some(Vec::new()),
+        // `let _ = v.drain(..);`
+        Node::LetStmt(local)
+            if let PatKind::Wild = local.pat.kind
+                && local.els.is_none()
+                && !local.span.from_expansion() =>
+        {
+            Some(vec![local.span.until(expr.span)])
+        },
+        Node::Expr(parent) if !parent.span.from_expansion() && is_semi_stmt_expr(cx, parent) => match parent.kind {
+            // `drop(v.drain(..));`
+            ExprKind::Call(func, [_]) if is_path_diagnostic_item(cx, func, sym::mem_drop) => {
+                Some(vec![parent.span.until(expr.span), parent.span.with_lo(expr.span.hi())])
+            },
+            // `v.drain(..).for_each(|_| {});`
+            ExprKind::MethodCall(segment, recv, [closure], _)
+                if segment.ident.name.as_str() == "for_each"
+                    && recv.hir_id == expr.hir_id
+                    && let ExprKind::Closure(closure) = closure.kind
+                    && let ExprKind::Block(block, _) = cx.tcx.hir().body(closure.body).value.kind
+                    && block.stmts.is_empty()
+                    && block.expr.is_none() =>
+            {
+                Some(vec![parent.span.with_lo(expr.span.hi())])
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_semi_stmt(stmt: &Stmt<'_>) -> bool {
+    matches!(stmt.kind, StmtKind::Semi(_))
+}
+
+fn is_semi_stmt_expr(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(cx.tcx.parent_hir_node(expr.hir_id), Node::Stmt(stmt) if is_semi_stmt(stmt))
+}
+
 fn match_acceptable_type(cx: &LateContext<'_>, expr: &Expr<'_>, types: &[rustc_span::Symbol]) -> bool {
     let expr_ty = cx.typeck_results().expr_ty(expr).peel_refs();
     types.iter().any(|&ty| is_type_diagnostic_item(cx, expr_ty, ty))
@@ -34,19 +84,33 @@ fn match_acceptable_type(cx: &LateContext<'_>, expr: &Expr<'_>, types: &[rustc_s
         || is_type_lang_item(cx, expr_ty, LangItem::String)
 }
 
-fn suggest(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, span: Span) {
+fn suggest(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, span: Span, removed_spans: Vec<Span>) {
     if let Some(adt) = cx.typeck_results().expr_ty(recv).ty_adt_def()
     // Use `opt_item_name` while `String` is not a diagnostic item
         && let Some(ty_name) = cx.tcx.opt_item_name(adt.did())
     {
-        span_lint_and_sugg(
-            cx,
-            CLEAR_WITH_DRAIN,
-            span.with_hi(expr.span.hi()),
-            format!("`drain` used to clear a `{ty_name}`"),
-            "try",
-            "clear()".to_string(),
-            Applicability::MachineApplicable,
-        );
+        let drain_span = span.with_hi(expr.span.hi());
+        let msg = format!("`drain` used to clear a `{ty_name}`");
+        if removed_spans.is_empty() {
+            span_lint_and_sugg(
+                cx,
+                CLEAR_WITH_DRAIN,
+                drain_span,
+                msg,
+                "try",
+                "clear()".to_string(),
+                Applicability::MachineApplicable,
+            );
+        } else {
+            let outer_span = removed_spans.iter().fold(drain_span, |acc, &span| acc.to(span));
+            span_lint_and_then(cx, CLEAR_WITH_DRAIN, outer_span, msg, |diag| {
+                let sugg = removed_spans
+                    .into_iter()
+                    .map(|span| (span, String::new()))
+                    .chain([(drain_span, "clear()".to_string())])
+                    .collect();
+                diag.multipart_suggestion("try", sugg, Applicability::MachineApplicable);
+            });
+        }
     }
 }
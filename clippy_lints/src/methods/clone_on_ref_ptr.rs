@@ -30,9 +30,27 @@ pub(super) fn check(
             _ => return,
         };
 
-        // Sometimes unnecessary ::<_> after Rc/Arc/Weak
-        let mut app = Applicability::Unspecified;
+        // The pointee type can be inferred unless the result is coerced, e.g. from `Rc<T>` to
+        // `Rc<dyn Trait>`, in which case inference would pick the type after the coercion.
+        let is_coerced = !cx.typeck_results().expr_adjustments(expr).is_empty();
+        // The type may not be in scope under this name, e.g. `Weak` is usually only reachable as
+        // `rc::Weak` or `sync::Weak`
+        let mut app = if is_coerced {
+            Applicability::Unspecified
+        } else {
+            Applicability::MaybeIncorrect
+        };
         let snippet = snippet_with_context(cx, receiver.span, expr.span.ctxt(), "..", &mut app).0;
+        let borrow = if cx.typeck_results().expr_ty(receiver).is_ref() {
+            ""
+        } else {
+            "&"
+        };
+        let sugg = if is_coerced {
+            format!("{caller_type}::<{}>::clone({borrow}{snippet})", subst.type_at(0))
+        } else {
+            format!("{caller_type}::clone({borrow}{snippet})")
+        };
 
         span_lint_and_sugg(
             cx,
@@ -40,7 +58,7 @@ pub(super) fn check(
             expr.span,
             "using `.clone()` on a ref-counted pointer",
             "try",
-            format!("{caller_type}::<{}>::clone(&{snippet})", subst.type_at(0)),
+            sugg,
             app,
         );
     }
@@ -1,5 +1,5 @@
 use clippy_config::msrvs::{self, Msrv};
-use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
 use clippy_utils::is_trait_method;
 use clippy_utils::source::snippet;
 use rustc_errors::Applicability;
@@ -7,6 +7,7 @@ use rustc_hir as hir;
 use rustc_lint::LateContext;
 use rustc_span::sym;
 
+use super::filter_next::immutable_binding;
 use super::FILTER_MAP_NEXT;
 
 pub(super) fn check<'tcx>(
@@ -26,15 +27,28 @@ pub(super) fn check<'tcx>(
         let filter_snippet = snippet(cx, arg.span, "..");
         if filter_snippet.lines().count() <= 1 {
             let iter_snippet = snippet(cx, recv.span, "..");
-            span_lint_and_sugg(
-                cx,
-                FILTER_MAP_NEXT,
-                expr.span,
-                msg,
-                "try",
-                format!("{iter_snippet}.find_map({filter_snippet})"),
-                Applicability::MachineApplicable,
-            );
+            span_lint_and_then(cx, FILTER_MAP_NEXT, expr.span, msg, |diag| {
+                let pat = immutable_binding(cx, recv);
+                let applicability = if pat.is_some() {
+                    Applicability::Unspecified
+                } else {
+                    Applicability::MachineApplicable
+                };
+
+                diag.span_suggestion(
+                    expr.span,
+                    "try",
+                    format!("{iter_snippet}.find_map({filter_snippet})"),
+                    applicability,
+                );
+
+                if let Some((pat_span, ident)) = pat {
+                    diag.span_help(
+                        pat_span,
+                        format!("you will also need to make `{ident}` mutable, because `find_map` takes `&mut self`"),
+                    );
+                }
+            });
         } else {
             span_lint(cx, FILTER_MAP_NEXT, expr.span, msg);
         }
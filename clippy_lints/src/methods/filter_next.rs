@@ -5,7 +5,8 @@ use rustc_ast::{BindingMode, Mutability};
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
-use rustc_span::sym;
+use rustc_span::symbol::Ident;
+use rustc_span::{sym, Span};
 
 use super::FILTER_NEXT;
 
@@ -24,6 +25,19 @@ fn path_to_local(expr: &hir::Expr<'_>) -> Option<hir::HirId> {
     }
 }
 
+/// Returns the pattern span and name of the binding if the iterator is a local that isn't bound
+/// mutably. Unlike `filter` and `filter_map`, the suggested `find` and `find_map` take `&mut self`.
+pub(super) fn immutable_binding(cx: &LateContext<'_>, recv: &hir::Expr<'_>) -> Option<(Span, Ident)> {
+    if let Some(id) = path_to_local(recv)
+        && let hir::Node::Pat(pat) = cx.tcx.hir_node(id)
+        && let hir::PatKind::Binding(BindingMode(_, Mutability::Not), _, ident, _) = pat.kind
+    {
+        Some((pat.span, ident))
+    } else {
+        None
+    }
+}
+
 /// lint use of `filter().next()` for `Iterators`
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
@@ -43,13 +57,11 @@ pub(super) fn check<'tcx>(
             let iter_snippet = snippet(cx, recv.span, "..");
             // add note if not multi-line
             span_lint_and_then(cx, FILTER_NEXT, expr.span, msg, |diag| {
-                let (applicability, pat) = if let Some(id) = path_to_local(recv)
-                    && let hir::Node::Pat(pat) = cx.tcx.hir_node(id)
-                    && let hir::PatKind::Binding(BindingMode(_, Mutability::Not), _, ident, _) = pat.kind
-                {
-                    (Applicability::Unspecified, Some((pat.span, ident)))
+                let pat = immutable_binding(cx, recv);
+                let applicability = if pat.is_some() {
+                    Applicability::Unspecified
                 } else {
-                    (Applicability::MachineApplicable, None)
+                    Applicability::MachineApplicable
                 };
 
                 diag.span_suggestion(
@@ -0,0 +1,85 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{higher, is_path_diagnostic_item, is_trait_method, path_to_local_id};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, BlockCheckMode, Closure, Expr, ExprKind, HirId, Node, PatKind, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::FOR_EACH_DROP;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+        && let body = cx.tcx.hir().body(body)
+        && let [param] = body.params
+        && discards_param(cx, param.pat.kind, body.value)
+        && let Node::Stmt(stmt) = cx.tcx.parent_hir_node(expr.hir_id)
+        && let StmtKind::Semi(_) = stmt.kind
+        && !stmt.span.from_expansion()
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let mut iter = snippet_with_context(cx, recv.span, stmt.span.ctxt(), "..", &mut applicability).0;
+        // `(0..10).for_each(..)` needs the parentheses, but the loop header doesn't
+        if higher::Range::hir(recv).is_some()
+            && let Some(inner) = iter.strip_prefix('(').and_then(|iter| iter.strip_suffix(')'))
+        {
+            iter = inner.to_owned().into();
+        }
+        span_lint_and_sugg(
+            cx,
+            FOR_EACH_DROP,
+            stmt.span,
+            "`for_each` used only to drop the items of an iterator",
+            "consume the iterator with a `for` loop",
+            format!("for _ in {iter} {{}}"),
+            applicability,
+        );
+    }
+}
+
+/// Checks if the closure body does nothing with its parameter besides dropping it, i.e. it is
+/// empty, `drop(x)` or `let _ = x;`.
+fn discards_param(cx: &LateContext<'_>, param: PatKind<'_>, value: &Expr<'_>) -> bool {
+    let param_id = match param {
+        PatKind::Binding(BindingMode::NONE, id, _, None) => Some(id),
+        _ => None,
+    };
+    match value.kind {
+        ExprKind::Tup([]) => true,
+        ExprKind::Block(block, None) if block.rules == BlockCheckMode::DefaultBlock => {
+            match (block.stmts, block.expr) {
+                ([], None) => true,
+                ([], Some(expr)) => is_drop_of(cx, expr, param_id),
+                ([stmt], None) => match stmt.kind {
+                    StmtKind::Semi(expr) => is_drop_of(cx, expr, param_id),
+                    StmtKind::Let(local) => {
+                        matches!(local.pat.kind, PatKind::Wild)
+                            && local.ty.is_none()
+                            && local.els.is_none()
+                            && local
+                                .init
+                                .is_some_and(|init| param_id.is_some_and(|id| path_to_local_id(init, id)))
+                    },
+                    _ => false,
+                },
+                _ => false,
+            }
+        },
+        _ => is_drop_of(cx, value, param_id),
+    }
+}
+
+/// Checks if `expr` is `drop(x)`, where `x` is the closure parameter. Dropping a reference is left
+/// to `dropping_references`.
+fn is_drop_of(cx: &LateContext<'_>, expr: &Expr<'_>, param_id: Option<HirId>) -> bool {
+    if let ExprKind::Call(func, [arg]) = expr.kind
+        && let Some(id) = param_id
+    {
+        is_path_diagnostic_item(cx, func, sym::mem_drop)
+            && path_to_local_id(arg, id)
+            && !cx.typeck_results().expr_ty(arg).is_ref()
+    } else {
+        false
+    }
+}
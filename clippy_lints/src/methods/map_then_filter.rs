@@ -0,0 +1,133 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{is_trait_method, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, Body, CaptureBy, Expr, ExprKind, HirId, Mutability, Pat, PatKind, TyKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::MAP_THEN_FILTER;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    map_expr: &'tcx Expr<'tcx>,
+    map_arg: &'tcx Expr<'tcx>,
+    map_span: Span,
+    filter_span: Span,
+    filter_arg: &'tcx Expr<'tcx>,
+) {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && is_trait_method(cx, map_expr, sym::Iterator)
+        && let ExprKind::Closure(map_closure) = map_arg.kind
+        && let map_body = cx.tcx.hir().body(map_closure.body)
+        // The captures would be mutated a different number of times
+        && let Some(mutated) = mutated_variables(map_body.value, cx)
+        && !cx
+            .typeck_results()
+            .closure_min_captures_flattened(map_closure.def_id)
+            .any(|capture| mutated.contains(&capture.get_root_variable()))
+        && let [map_param] = map_body.params
+        && let PatKind::Binding(BindingMode::NONE, map_param_id, _, None) = map_param.pat.kind
+        && let ExprKind::Closure(filter_closure) = filter_arg.kind
+        && filter_closure.capture_clause == CaptureBy::Ref
+        && let [input] = filter_closure.fn_decl.inputs
+        && let TyKind::Infer = input.kind
+        && let filter_body = cx.tcx.hir().body(filter_closure.body)
+        && let [filter_param] = filter_body.params
+    {
+        let mut applicability = Applicability::MaybeIncorrect;
+        let Some(pat) = pattern_before_map(
+            cx,
+            filter_param.pat,
+            filter_body,
+            map_body,
+            map_param_id,
+            &mut applicability,
+        ) else {
+            return;
+        };
+        let filter_body = snippet_with_applicability(cx, filter_body.value.span, "..", &mut applicability);
+        let map_arg = snippet_with_applicability(cx, map_arg.span, "..", &mut applicability);
+        // Keeps the calls on separate lines if they were
+        let separator = snippet_with_applicability(
+            cx,
+            map_expr.span.with_lo(map_expr.span.hi()).with_hi(filter_span.lo()),
+            ".",
+            &mut applicability,
+        );
+        span_lint_and_sugg(
+            cx,
+            MAP_THEN_FILTER,
+            map_span.with_hi(expr.span.hi()),
+            "this `filter` doesn't depend on the values computed by the `map` closure",
+            "filter the elements before mapping them",
+            format!("filter(|{pat}| {filter_body}){separator}map({map_arg})"),
+            applicability,
+        );
+    }
+}
+
+/// Builds the pattern the `filter` closure would take if it ran before the `map` closure. This is
+/// possible when it ignores its argument, or when the `map` closure returns a tuple and only the
+/// element holding the `map` closure's argument is used, e.g.
+/// `.map(|x| (x, f(x))).filter(|(x, _)| ..)`, in which case the pattern of that element is used.
+fn pattern_before_map<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'tcx>,
+    filter_body: &'tcx Body<'tcx>,
+    map_body: &'tcx Body<'tcx>,
+    map_param_id: HirId,
+    applicability: &mut Applicability,
+) -> Option<String> {
+    if is_unused(cx, pat, filter_body) {
+        return Some("_".to_owned());
+    }
+    let (pat, ref_pat) = if let PatKind::Ref(inner, Mutability::Not) = pat.kind {
+        (inner, "&")
+    } else {
+        (pat, "")
+    };
+    let PatKind::Tuple(pats, dotdot) = pat.kind else {
+        return None;
+    };
+    let ExprKind::Tup(elems) = peel_blocks(map_body.value).kind else {
+        return None;
+    };
+    if dotdot.as_opt_usize().is_some() || pats.len() != elems.len() {
+        return None;
+    }
+
+    let mut used = None;
+    for (pat, elem) in pats.iter().zip(elems) {
+        if is_unused(cx, pat, filter_body) {
+            continue;
+        }
+        if used.is_some()
+            || !path_to_local_id(elem, map_param_id)
+            || !matches!(pat.kind, PatKind::Binding(BindingMode::NONE, _, _, None))
+        {
+            return None;
+        }
+        used = Some(pat);
+    }
+    Some(used.map_or_else(
+        || "_".to_owned(),
+        |pat| {
+            format!(
+                "{ref_pat}{}",
+                snippet_with_applicability(cx, pat.span, "..", applicability)
+            )
+        },
+    ))
+}
+
+fn is_unused<'tcx>(cx: &LateContext<'tcx>, pat: &Pat<'_>, body: &'tcx Body<'tcx>) -> bool {
+    match pat.kind {
+        PatKind::Wild => true,
+        PatKind::Binding(_, id, _, None) => !is_local_used(cx, body.value, id),
+        _ => false,
+    }
+}
@@ -5,10 +5,12 @@ mod bytes_nth;
 mod case_sensitive_file_extension_comparisons;
 mod chars_cmp;
 mod chars_cmp_with_unwrap;
+mod chars_collect_to_string;
 mod chars_last_cmp;
 mod chars_last_cmp_with_unwrap;
 mod chars_next_cmp;
 mod chars_next_cmp_with_unwrap;
+mod chars_nth_for_ascii;
 mod clear_with_drain;
 mod clone_on_copy;
 mod clone_on_ref_ptr;
@@ -26,6 +28,7 @@ mod filter_map_next;
 mod filter_next;
 mod flat_map_identity;
 mod flat_map_option;
+mod for_each_drop;
 mod format_collect;
 mod from_iter_instead_of_collect;
 mod get_first;
@@ -65,6 +68,7 @@ mod map_collect_result_unit;
 mod map_err_ignore;
 mod map_flatten;
 mod map_identity;
+mod map_then_filter;
 mod map_unwrap_or;
 mod mut_mutex_lock;
 mod needless_character_iteration;
@@ -123,12 +127,14 @@ mod unnecessary_sort_by;
 mod unnecessary_to_owned;
 mod unused_enumerate_index;
 mod unwrap_expect_used;
+mod unwrap_known_parseable;
 mod useless_asref;
 mod utils;
 mod vec_resize_to_zero;
 mod verbose_file_reads;
 mod waker_clone_wake;
 mod wrong_self_convention;
+mod zip_explicit_iter;
 mod zst_offset;
 
 use bind_instead_of_map::BindInsteadOfMap;
@@ -142,7 +148,7 @@ use clippy_utils::{contains_return, is_bool, is_trait_method, iter_input_pats, p
 pub use path_ends_with_ext::DEFAULT_ALLOWED_DOTFILES;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
-use rustc_hir::{Expr, ExprKind, Node, Stmt, StmtKind, TraitItem, TraitItemKind};
+use rustc_hir::{Expr, ExprKind, TraitItem, TraitItemKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{self, TraitRef, Ty};
@@ -502,7 +508,7 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for usages of the following functions with an argument that constructs a default value
-    /// (e.g., `Default::default` or `String::new`):
+    /// (e.g., `Default::default` or `String::new`, or a closure calling them):
     /// - `unwrap_or`
     /// - `unwrap_or_else`
     /// - `or_insert`
@@ -3299,6 +3305,9 @@ declare_clippy_lint! {
     /// ### What it does
     /// Checks for usage of `.drain(..)` for the sole purpose of clearing a container.
     ///
+    /// The iterator is considered unused when the call is a statement on its own, bound to `_`,
+    /// passed to `drop` or consumed by a `for_each` with an empty closure.
+    ///
     /// ### Why is this bad?
     /// This creates an unnecessary iterator that is dropped immediately.
     ///
@@ -4127,6 +4136,181 @@ declare_clippy_lint! {
     "use of `map` returning the original item"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for_each` calls on an iterator with a closure that does nothing but drop its
+    /// argument, such as `|x| drop(x)`, `|_| {}` or `|x| { let _ = x; }`.
+    ///
+    /// ### Why is this bad?
+    /// The closure only exists to consume the iterator, which a `for` loop with an empty body
+    /// expresses more directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let v = vec![String::new()];
+    /// v.into_iter().for_each(|x| drop(x));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let v = vec![String::new()];
+    /// for _ in v.into_iter() {}
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub FOR_EACH_DROP,
+    style,
+    "`for_each` with a closure that only drops its argument"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.zip(collection.iter())` where the collection, or a reference to it, can be
+    /// passed to `zip` directly.
+    ///
+    /// ### Why is this bad?
+    /// `zip` accepts any `IntoIterator`, so the call to `iter` is redundant.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let a = vec![1, 2];
+    /// # let b = vec![3, 4];
+    /// let _ = a.iter().zip(b.iter());
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let a = vec![1, 2];
+    /// # let b = vec![3, 4];
+    /// let _ = a.iter().zip(&b);
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub ZIP_EXPLICIT_ITER,
+    style,
+    "calling `iter` on the argument of `zip`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.chars().collect::<String>()` on a `&str` or a `String`.
+    ///
+    /// ### Why is this bad?
+    /// Collecting the characters builds a copy of the string one `char` at a time, which
+    /// `to_owned` or `clone` do directly.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let s = "abc";
+    /// let owned: String = s.chars().collect();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let s = "abc";
+    /// let owned: String = s.to_owned();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CHARS_COLLECT_TO_STRING,
+    style,
+    "collecting the `chars` of a string into a `String` instead of copying it"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.map(f).filter(g)` on iterators where `g` doesn't use the values computed by
+    /// `f`, i.e. it ignores its argument, or `f` returns a tuple and `g` only uses the element
+    /// holding the argument of `f`.
+    ///
+    /// ### Why is this bad?
+    /// `f` is called on every element, including the ones `g` then discards. Filtering the
+    /// elements first only calls `f` on the ones which are kept.
+    ///
+    /// `f` has to be a closure which doesn't mutate the variables it captures.
+    ///
+    /// ### Known problems
+    /// The suggestion changes the elements `f` is called on, which differs if `f` still has side
+    /// effects, e.g. by calling a function which has some or through interior mutability.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # fn expensive(x: u32) -> u32 { x }
+    /// # let v = vec![1, 2, 3];
+    /// let _: Vec<_> = v
+    ///     .into_iter()
+    ///     .map(|x| (x, expensive(x)))
+    ///     .filter(|(x, _)| *x > 1)
+    ///     .collect();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn expensive(x: u32) -> u32 { x }
+    /// # let v = vec![1, 2, 3];
+    /// let _: Vec<_> = v
+    ///     .into_iter()
+    ///     .filter(|x| *x > 1)
+    ///     .map(|x| (x, expensive(x)))
+    ///     .collect();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MAP_THEN_FILTER,
+    perf,
+    "`.map(f).filter(g)` where `g` doesn't depend on `f`, instead of filtering first"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.parse().unwrap()` on a string literal which is known to parse successfully,
+    /// i.e. numbers, `bool`s, IP addresses and socket addresses.
+    ///
+    /// ### Why is this bad?
+    /// The `unwrap` can never panic, but a reader has to check the literal to know this. Writing
+    /// the value directly makes it obvious, and doesn't parse it at runtime.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::net::Ipv4Addr;
+    /// let port = "8080".parse::<u16>().unwrap();
+    /// let addr = "192.168.0.1".parse::<Ipv4Addr>().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::net::Ipv4Addr;
+    /// let port = 8080_u16;
+    /// let addr = Ipv4Addr::new(192, 168, 0, 1);
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub UNWRAP_KNOWN_PARSEABLE,
+    style,
+    "unwrapping the result of parsing a string literal which is known to be valid"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the use of `.chars().nth()` on a `str` or `String`.
+    ///
+    /// ### Why restrict this?
+    /// `.chars().nth(n)` has to decode the first `n` characters of the string, as they may take
+    /// several bytes each. If the string is known to contain only ASCII characters, indexing its
+    /// bytes with `.as_bytes()[n]` gives the same character in constant time.
+    ///
+    /// ### Known problems
+    /// The lint can't tell whether the string is ASCII-only. For a string containing any other
+    /// character, the suggestion gives a different result, so it should only be applied after
+    /// checking the string, e.g. with `str::is_ascii`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let s = "Hello";
+    /// let c = s.chars().nth(3).unwrap();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let s = "Hello";
+    /// assert!(s.is_ascii());
+    /// let c = s.as_bytes()[3] as char;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CHARS_NTH_FOR_ASCII,
+    restriction,
+    "using `.chars().nth()` on a string which is only expected to contain ASCII characters"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
@@ -4287,6 +4471,12 @@ impl_lint_pass!(Methods => [
     NEEDLESS_CHARACTER_ITERATION,
     MANUAL_INSPECT,
     UNNECESSARY_MIN_OR_MAX,
+    FOR_EACH_DROP,
+    ZIP_EXPLICIT_ITER,
+    CHARS_COLLECT_TO_STRING,
+    MAP_THEN_FILTER,
+    UNWRAP_KNOWN_PARSEABLE,
+    CHARS_NTH_FOR_ASCII,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4571,6 +4761,9 @@ impl Methods {
                         Some(("drain", recv, args, ..)) => {
                             drain_collect::check(cx, args, expr, recv);
                         },
+                        Some(("chars", recv, [], _, _)) => {
+                            chars_collect_to_string::check(cx, expr, recv);
+                        },
                         _ => {},
                     }
                 },
@@ -4590,11 +4783,10 @@ impl Methods {
                     unnecessary_min_or_max::check(cx, expr, name, recv, arg);
                 },
                 ("drain", ..) => {
-                    if let Node::Stmt(Stmt { hir_id: _, kind, .. }) = cx.tcx.parent_hir_node(expr.hir_id)
-                        && matches!(kind, StmtKind::Semi(_))
-                        && args.len() <= 1
+                    if args.len() <= 1
+                        && let Some(removed_spans) = clear_with_drain::discarded_drain(cx, expr)
                     {
-                        clear_with_drain::check(cx, expr, recv, span, args.first());
+                        clear_with_drain::check(cx, expr, recv, span, args.first(), removed_spans);
                     } else if let [arg] = args {
                         iter_with_drain::check(cx, expr, recv, span, arg);
                     }
@@ -4638,6 +4830,9 @@ impl Methods {
                     extend_with_drain::check(cx, expr, recv, arg);
                 },
                 ("filter", [arg]) => {
+                    if let Some(("map", _, [map_arg], map_span, _)) = method_call(recv) {
+                        map_then_filter::check(cx, expr, recv, map_arg, map_span, span, arg);
+                    }
                     if let Some(("cloned", recv2, [], _span2, _)) = method_call(recv) {
                         // if `arg` has side-effect, the semantic will change
                         iter_overeager_cloned::check(
@@ -4701,6 +4896,7 @@ impl Methods {
                     unnecessary_fold::check(cx, expr, init, acc, span);
                 },
                 ("for_each", [arg]) => {
+                    for_each_drop::check(cx, expr, recv, arg);
                     unused_enumerate_index::check(cx, expr, recv, arg);
                     match method_call(recv) {
                         Some(("inspect", _, [_], span2, _)) => inspect_for_each::check(cx, expr, span2),
@@ -4827,6 +5023,7 @@ impl Methods {
                 },
                 ("nth", [n_arg]) => match method_call(recv) {
                     Some(("bytes", recv2, [], _, _)) => bytes_nth::check(cx, expr, recv2, n_arg),
+                    Some(("chars", recv2, [], _, _)) => chars_nth_for_ascii::check(cx, expr, recv2, n_arg),
                     Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(
                         cx,
                         expr,
@@ -4972,6 +5169,9 @@ impl Methods {
                         Some(("or", recv, [or_arg], or_span, _)) => {
                             or_then_unwrap::check(cx, expr, recv, or_arg, or_span);
                         },
+                        Some(("parse", parse_recv, [], _, _)) => {
+                            unwrap_known_parseable::check(cx, expr, recv, parse_recv);
+                        },
                         _ => {},
                     }
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
@@ -5041,6 +5241,7 @@ impl Methods {
                     {
                         range_zip_with_len::check(cx, expr, iter_recv, arg);
                     }
+                    zip_explicit_iter::check(cx, expr, arg);
                 },
                 _ => {},
             }
@@ -30,13 +30,14 @@ pub(super) fn check<'tcx>(
     /// Checks for `unwrap_or(T::new())`, `unwrap_or(T::default())`,
     /// `or_insert(T::new())` or `or_insert(T::default())`.
     /// Similarly checks for `unwrap_or_else(T::new)`, `unwrap_or_else(T::default)`,
-    /// `or_insert_with(T::new)` or `or_insert_with(T::default)`.
-    fn check_unwrap_or_default(
-        cx: &LateContext<'_>,
+    /// `or_insert_with(T::new)` or `or_insert_with(T::default)`, and the same functions called
+    /// from a closure, e.g. `or_insert_with(|| T::new())`.
+    fn check_unwrap_or_default<'tcx>(
+        cx: &LateContext<'tcx>,
         name: &str,
         receiver: &hir::Expr<'_>,
-        fun: &hir::Expr<'_>,
-        call_expr: Option<&hir::Expr<'_>>,
+        fun: &'tcx hir::Expr<'tcx>,
+        call_expr: Option<&'tcx hir::Expr<'tcx>>,
         span: Span,
         method_span: Span,
     ) -> bool {
@@ -73,6 +74,16 @@ pub(super) fn check<'tcx>(
             _ => return false,
         };
 
+        // A closure only calling a function is checked like the function itself
+        let (fun, call_expr) = match call_expr {
+            None if let Some(call) = closure_body_call(cx, fun)
+                && let hir::ExprKind::Call(inner_fun, []) = call.kind =>
+            {
+                (inner_fun, Some(call))
+            },
+            _ => (fun, call_expr),
+        };
+
         let receiver_ty = cx.typeck_results().expr_ty_adjusted(receiver).peel_refs();
         let Some(suggested_method_def_id) = receiver_ty.ty_adt_def().and_then(|adt_def| {
             cx.tcx
@@ -254,6 +265,20 @@ pub(super) fn check<'tcx>(
     }
 }
 
+/// Returns the body of a closure without parameters if it consists of a call without arguments.
+fn closure_body_call<'tcx>(cx: &LateContext<'tcx>, e: &hir::Expr<'_>) -> Option<&'tcx hir::Expr<'tcx>> {
+    if let hir::ExprKind::Closure(&hir::Closure { body, .. }) = e.kind
+        && let body = cx.tcx.hir().body(body)
+        && body.params.is_empty()
+        && let value = peel_blocks(body.value)
+        && let hir::ExprKind::Call(_, []) = value.kind
+    {
+        Some(value)
+    } else {
+        None
+    }
+}
+
 fn closure_body_returns_empty_to_string(cx: &LateContext<'_>, e: &hir::Expr<'_>) -> bool {
     if let hir::ExprKind::Closure(&hir::Closure { body, .. }) = e.kind {
         let body = cx.tcx.hir().body(body);
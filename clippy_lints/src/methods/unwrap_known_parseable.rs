@@ -0,0 +1,148 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::{get_parent_expr, paths, std_or_core};
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::IntegerExt;
+use rustc_middle::ty::{self, FloatTy, Ty};
+use rustc_span::sym;
+use rustc_target::abi::Integer;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4};
+
+use super::UNWRAP_KNOWN_PARSEABLE;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, parse_recv: &Expr<'_>) {
+    if let ExprKind::Lit(lit) = parse_recv.kind
+        && let LitKind::Str(text, _) = lit.node
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result)
+        && let Some(value) = parsed_value(cx, text.as_str(), cx.typeck_results().expr_ty(expr))
+    {
+        // A negative literal needs parentheses when a method is called on it, e.g. `(-1_i32).abs()`
+        let value = if value.starts_with('-')
+            && let Some(parent) = get_parent_expr(cx, expr)
+            && let ExprKind::MethodCall(_, base, ..) | ExprKind::Field(base, _) | ExprKind::Index(base, ..) =
+                parent.kind
+            && base.hir_id == expr.hir_id
+        {
+            format!("({value})")
+        } else {
+            value
+        };
+        span_lint_and_sugg(
+            cx,
+            UNWRAP_KNOWN_PARSEABLE,
+            expr.span,
+            "unwrapping the result of parsing a string literal which is known to be valid",
+            "use the value directly",
+            value,
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// Parses the text as the given type, returning the expression for the value if it succeeds.
+fn parsed_value<'tcx>(cx: &LateContext<'tcx>, text: &str, ty: Ty<'tcx>) -> Option<String> {
+    match ty.kind() {
+        ty::Int(int_ty) => {
+            let value: i128 = text.parse().ok()?;
+            let size = Integer::from_int_ty(&cx.tcx, *int_ty).size();
+            (size.signed_int_min() <= value && value <= size.signed_int_max())
+                .then(|| format!("{value}_{}", int_ty.name_str()))
+        },
+        ty::Uint(uint_ty) => {
+            let value: u128 = text.parse().ok()?;
+            let size = Integer::from_uint_ty(&cx.tcx, *uint_ty).size();
+            (value <= size.unsigned_int_max()).then(|| format!("{value}_{}", uint_ty.name_str()))
+        },
+        ty::Float(float_ty @ (FloatTy::F32 | FloatTy::F64)) => {
+            // Only decimal numbers are written the same way as a float literal
+            let digits = text.strip_prefix('-').unwrap_or(text);
+            let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, "0"));
+            let is_decimal = |digits: &str| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+            let is_finite = if *float_ty == FloatTy::F32 {
+                text.parse::<f32>().is_ok_and(f32::is_finite)
+            } else {
+                text.parse::<f64>().is_ok_and(f64::is_finite)
+            };
+            (is_decimal(int_part) && is_decimal(frac_part) && is_finite)
+                .then(|| format!("{text}_{}", float_ty.name_str()))
+        },
+        ty::Bool => text.parse::<bool>().ok().map(|value| value.to_string()),
+        ty::Adt(..) => {
+            let net = format!("{}::net", std_or_core(cx)?);
+            if is_type_diagnostic_item(cx, ty, sym::IpAddr) {
+                Some(ip_addr(&net, text.parse().ok()?))
+            } else if match_type(cx, ty, &paths::IPV4_ADDR) {
+                Some(ipv4_addr(&net, text.parse().ok()?))
+            } else if match_type(cx, ty, &paths::IPV6_ADDR) {
+                Some(ipv6_addr(&net, text.parse().ok()?))
+            } else if match_type(cx, ty, &paths::SOCKET_ADDR_V4) {
+                let addr: SocketAddrV4 = text.parse().ok()?;
+                Some(format!(
+                    "{net}::SocketAddrV4::new({}, {})",
+                    ipv4_addr(&net, *addr.ip()),
+                    addr.port()
+                ))
+            } else if match_type(cx, ty, &paths::SOCKET_ADDR) {
+                let addr: SocketAddr = text.parse().ok()?;
+                // `SocketAddr::new` can't set these
+                if let SocketAddr::V6(addr) = addr
+                    && (addr.flowinfo() != 0 || addr.scope_id() != 0)
+                {
+                    return None;
+                }
+                Some(format!(
+                    "{net}::SocketAddr::new({}, {})",
+                    ip_addr(&net, addr.ip()),
+                    addr.port()
+                ))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+fn ip_addr(net: &str, addr: IpAddr) -> String {
+    match addr {
+        IpAddr::V4(addr) => format!("{net}::IpAddr::V4({})", ipv4_addr(net, addr)),
+        IpAddr::V6(addr) => format!("{net}::IpAddr::V6({})", ipv6_addr(net, addr)),
+    }
+}
+
+fn ipv4_addr(net: &str, addr: Ipv4Addr) -> String {
+    match addr {
+        Ipv4Addr::LOCALHOST => format!("{net}::Ipv4Addr::LOCALHOST"),
+        Ipv4Addr::UNSPECIFIED => format!("{net}::Ipv4Addr::UNSPECIFIED"),
+        Ipv4Addr::BROADCAST => format!("{net}::Ipv4Addr::BROADCAST"),
+        _ => {
+            let [a, b, c, d] = addr.octets();
+            format!("{net}::Ipv4Addr::new({a}, {b}, {c}, {d})")
+        },
+    }
+}
+
+fn ipv6_addr(net: &str, addr: Ipv6Addr) -> String {
+    match addr {
+        Ipv6Addr::LOCALHOST => format!("{net}::Ipv6Addr::LOCALHOST"),
+        Ipv6Addr::UNSPECIFIED => format!("{net}::Ipv6Addr::UNSPECIFIED"),
+        _ => {
+            let segments = addr
+                .segments()
+                .iter()
+                .map(|&segment| {
+                    if segment == 0 {
+                        "0".to_owned()
+                    } else {
+                        format!("{segment:#x}")
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{net}::Ipv6Addr::new({segments})")
+        },
+    }
+}
@@ -0,0 +1,59 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_trait_method;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{get_iterator_item_ty, implements_trait, is_copy, make_normalized_projection};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+use rustc_span::sym;
+
+use super::ZIP_EXPLICIT_ITER;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, zip_arg: &'tcx Expr<'_>) {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && let ExprKind::MethodCall(name, collection, [], _) = zip_arg.kind
+        && name.ident.name == sym::iter
+        && !zip_arg.span.from_expansion()
+        && let Some(item_ty) = get_iterator_item_ty(cx, cx.typeck_results().expr_ty(zip_arg))
+    {
+        let item_ty = cx.tcx.erase_regions(item_ty);
+        let collection_ty = cx.typeck_results().expr_ty(collection);
+        // Passing the collection by value only yields the same items if it's a reference, e.g. a
+        // slice, and it's only equivalent if it isn't moved.
+        let by_value = is_copy(cx, collection_ty) && yields(cx, collection_ty, item_ty);
+        let by_ref = !by_value
+            && yields(
+                cx,
+                Ty::new_imm_ref(cx.tcx, cx.tcx.lifetimes.re_erased, collection_ty),
+                item_ty,
+            );
+        if !by_value && !by_ref {
+            return;
+        }
+
+        let mut applicability = Applicability::MachineApplicable;
+        let sugg = Sugg::hir_with_context(cx, collection, zip_arg.span.ctxt(), "..", &mut applicability);
+        let sugg = if by_ref { sugg.addr() } else { sugg };
+        span_lint_and_sugg(
+            cx,
+            ZIP_EXPLICIT_ITER,
+            zip_arg.span,
+            "`zip` accepts any `IntoIterator`, calling `iter` is unnecessary",
+            "try",
+            sugg.to_string(),
+            applicability,
+        );
+    }
+}
+
+/// Checks whether the type implements `IntoIterator` with the given item type.
+fn yields<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, item_ty: Ty<'tcx>) -> bool {
+    cx.tcx
+        .get_diagnostic_item(sym::IntoIterator)
+        .is_some_and(|into_iter_id| {
+            implements_trait(cx, ty, into_iter_id, &[])
+                && make_normalized_projection(cx.tcx, cx.param_env, into_iter_id, sym::Item, [ty])
+                    .is_some_and(|ty| cx.tcx.erase_regions(ty) == item_ty)
+        })
+}
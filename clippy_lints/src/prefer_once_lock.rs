@@ -0,0 +1,241 @@
+use clippy_config::msrvs::{self, Msrv};
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::macros::macro_backtrace;
+use clippy_utils::source::{indent_of, reindent_multiline, snippet_with_applicability, walk_span_to_context};
+use rustc_ast::{AttrArgs, AttrArgsEq, AttrItem, AttrKind};
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{ExprKind, FnRetTy, Item, ItemKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for uses of the `lazy_static!` macro of the `lazy_static` crate.
+    ///
+    /// ### Why is this bad?
+    /// Since Rust 1.70, `std::sync::OnceLock` lazily initializes a `static` without a
+    /// third-party dependency.
+    ///
+    /// ### Known problems
+    /// The value is initialized by `get_or_init` on first use, so every use of the static needs
+    /// to go through the accessor function of the suggestion, instead of dereferencing the static.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// lazy_static! {
+    ///     static ref NAMES: Vec<String> = load_names();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// fn names() -> &'static Vec<String> {
+    ///     static NAMES: std::sync::OnceLock<Vec<String>> = std::sync::OnceLock::new();
+    ///     NAMES.get_or_init(load_names)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub PREFER_ONCE_LOCK,
+    pedantic,
+    "`lazy_static!` where a `static` with `std::sync::OnceLock` can be used"
+}
+
+/// A `static ref` declared in a `lazy_static!` invocation.
+struct LazyStatic {
+    /// The struct `lazy_static!` generates for the static, which carries its name, visibility and
+    /// attributes.
+    def_id: LocalDefId,
+    name: String,
+    /// The attributes of the static, without the surrounding `#[...]` which comes from the macro.
+    attr_spans: Vec<Span>,
+    ty_span: Span,
+    init_span: Span,
+    /// Whether the initializer is a call without arguments, whose function can be passed to
+    /// `get_or_init` as is. The span of the initializer is then the span of the function.
+    init_is_fn: bool,
+}
+
+pub struct PreferOnceLock {
+    msrv: Msrv,
+    /// The statics of each `lazy_static!` invocation, keyed by the span of the invocation. The
+    /// invocation is replaced as a whole, so it's reported once all of its statics are known.
+    invocations: FxIndexMap<Span, Vec<LazyStatic>>,
+}
+
+impl PreferOnceLock {
+    pub fn new(conf: &'static Conf) -> Self {
+        Self {
+            msrv: conf.msrv.clone(),
+            invocations: FxIndexMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(PreferOnceLock => [PREFER_ONCE_LOCK]);
+
+impl<'tcx> LateLintPass<'tcx> for PreferOnceLock {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        // `lazy_static!` moves the initializer into a function of that name, nested in the `Deref`
+        // impl of a struct named after the static
+        if let ItemKind::Fn(sig, _, body_id) = item.kind
+            && item.ident.as_str() == "__static_ref_initialize"
+            && self.msrv.meets(msrvs::ONCE_LOCK)
+            && let Some(call) = macro_backtrace(item.span)
+                .filter(|call| {
+                    cx.tcx.item_name(call.def_id).as_str() == "lazy_static"
+                        && cx.tcx.crate_name(call.def_id.krate).as_str() == "lazy_static"
+                })
+                .last()
+            && !call.span.from_expansion()
+            && let FnRetTy::Return(ty) = sig.decl.output
+            && let ExprKind::Block(block, _) = cx.tcx.hir().body(body_id).value.kind
+            && let Some(init) = block.expr
+            && let (init, init_is_fn) = match init.kind {
+                ExprKind::Call(func, []) if matches!(func.kind, ExprKind::Path(_)) => (func, true),
+                _ => (init, false),
+            }
+            && let Some(ty_span) = walk_span_to_context(ty.span, call.span.ctxt())
+            && let Some(init_span) = walk_span_to_context(init.span, call.span.ctxt())
+            && let Some(lazy_struct) = generated_struct(cx, item)
+        {
+            self.invocations.entry(call.span).or_default().push(LazyStatic {
+                def_id: lazy_struct.owner_id.def_id,
+                name: lazy_struct.ident.to_string(),
+                attr_spans: cx
+                    .tcx
+                    .hir()
+                    .attrs(lazy_struct.hir_id())
+                    .iter()
+                    .filter_map(|attr| match &attr.kind {
+                        AttrKind::Normal(normal) => Some(attr_item_span(&normal.item)),
+                        AttrKind::DocComment(..) => None,
+                    })
+                    .filter(|span| !span.from_expansion())
+                    .collect(),
+                ty_span,
+                init_span,
+                init_is_fn,
+            });
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (call_span, statics) in std::mem::take(&mut self.invocations) {
+            let mut app = Applicability::MaybeIncorrect;
+            let indent = " ".repeat(indent_of(cx, call_span).unwrap_or(0));
+            let sugg = statics
+                .iter()
+                .map(|lazy_static| {
+                    reindent_multiline(
+                        once_lock_sugg(cx, lazy_static, &mut app).into(),
+                        true,
+                        Some(indent.len()),
+                    )
+                    .into_owned()
+                })
+                .collect::<Vec<_>>()
+                .join(&format!("\n\n{indent}"));
+            let uses = statics
+                .iter()
+                .map(|lazy_static| format!("`{}` with `{}()`", lazy_static.name, lazy_static.name.to_lowercase()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            span_lint_hir_and_then(
+                cx,
+                PREFER_ONCE_LOCK,
+                cx.tcx.local_def_id_to_hir_id(statics[0].def_id),
+                call_span,
+                "use of `lazy_static!`",
+                |diag| {
+                    diag.span_suggestion(call_span, "consider using `std::sync::OnceLock`", sugg, app);
+                    diag.note(format!("uses of the statics then need to be replaced: {uses}"));
+                },
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Finds the struct `lazy_static!` generates for the static, i.e. the `Self` type of the impl
+/// containing the initializer function.
+fn generated_struct<'tcx>(cx: &LateContext<'tcx>, init_fn: &Item<'_>) -> Option<&'tcx Item<'tcx>> {
+    let impl_id = cx
+        .tcx
+        .hir()
+        .parent_iter(init_fn.hir_id())
+        .find_map(|(_, node)| match node {
+            Node::Item(item) if matches!(item.kind, ItemKind::Impl(_)) => Some(item.owner_id),
+            _ => None,
+        })?;
+    let adt = cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def()?;
+    Some(cx.tcx.hir().expect_item(adt.did().as_local()?))
+}
+
+/// The span of an attribute without the surrounding `#[...]`. For a doc comment, this is the
+/// span of the comment.
+fn attr_item_span(item: &AttrItem) -> Span {
+    match &item.args {
+        AttrArgs::Empty => item.path.span,
+        AttrArgs::Delimited(args) => item.path.span.to(args.dspan.entire()),
+        AttrArgs::Eq(_, AttrArgsEq::Ast(expr)) => item.path.span.to(expr.span),
+        AttrArgs::Eq(_, AttrArgsEq::Hir(lit)) => item.path.span.to(lit.span),
+    }
+}
+
+/// Builds an accessor function initializing a `OnceLock` on first use.
+fn once_lock_sugg(cx: &LateContext<'_>, lazy_static: &LazyStatic, app: &mut Applicability) -> String {
+    let attrs: String = lazy_static
+        .attr_spans
+        .iter()
+        .map(|&span| {
+            let attr = snippet_with_applicability(cx, span, "..", app);
+            // Doc comments are passed to the macro as they were written
+            if attr.starts_with("//") || attr.starts_with("/*") {
+                format!("{attr}\n")
+            } else {
+                format!("#[{attr}]\n")
+            }
+        })
+        .collect();
+    let vis = visibility(cx, lazy_static.def_id);
+    let name = &lazy_static.name;
+    let ty = snippet_with_applicability(cx, lazy_static.ty_span, "..", app);
+    let init = snippet_with_applicability(cx, lazy_static.init_span, "..", app);
+    let init = if lazy_static.init_is_fn {
+        init.into_owned()
+    } else {
+        format!("|| {init}")
+    };
+    format!(
+        "{attrs}{vis}fn {}() -> &'static {ty} {{\n    \
+            static {name}: std::sync::OnceLock<{ty}> = std::sync::OnceLock::new();\n    \
+            {name}.get_or_init({init})\n\
+        }}",
+        name.to_lowercase(),
+    )
+}
+
+/// Renders the visibility of the static. It can't be taken from the source, as `lazy_static!`
+/// matches `pub` itself.
+fn visibility(cx: &LateContext<'_>, def_id: LocalDefId) -> String {
+    match cx.tcx.visibility(def_id) {
+        ty::Visibility::Public => "pub ".to_owned(),
+        ty::Visibility::Restricted(module) => {
+            let parent = cx.tcx.parent_module_from_def_id(def_id).to_def_id();
+            if module == parent {
+                String::new()
+            } else if module.is_crate_root() {
+                "pub(crate) ".to_owned()
+            } else if cx.tcx.opt_parent(parent) == Some(module) {
+                "pub(super) ".to_owned()
+            } else {
+                format!("pub(in crate::{}) ", cx.tcx.def_path_str(module))
+            }
+        },
+    }
+}
@@ -0,0 +1,195 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::{snippet, snippet_opt};
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{get_parent_expr, is_expr_path_def_path, match_def_path, path_to_local, path_to_local_id, paths};
+use core::ops::ControlFlow;
+use rustc_ast::{LitKind, StrStyle};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, Block, BorrowKind, Expr, ExprKind, HirId, LetStmt, Mutability, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::{Span, Symbol};
+use std::fmt::Write as _;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a `String` created with `String::new()` which is immediately built up by three
+    /// or more calls to `push_str`.
+    ///
+    /// ### Why is this bad?
+    /// A single `format!` call shows the resulting string at a glance, and doesn't need the
+    /// binding to be mutable.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let name = "world";
+    /// let mut s = String::new();
+    /// s.push_str("Hello, ");
+    /// s.push_str(name);
+    /// s.push_str("!");
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let name = "world";
+    /// let s = format!("Hello, {name}!");
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub PUSH_STR_TO_FORMAT,
+    pedantic,
+    "`push_str` calls immediately after `String::new()`, which could be a `format!`"
+}
+
+declare_lint_pass!(PushStrToFormat => [PUSH_STR_TO_FORMAT]);
+
+/// The number of `push_str` calls needed for the lint to trigger.
+const MIN_PUSHES: usize = 3;
+
+impl<'tcx> LateLintPass<'tcx> for PushStrToFormat {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Let(local) = stmt.kind
+                && let Some((local_id, name)) = string_new_binding(cx, local)
+            {
+                let pushes: Vec<_> = block.stmts[i + 1..]
+                    .iter()
+                    .map_while(|stmt| push_str_arg(cx, stmt, local_id))
+                    .collect();
+                if let [.., (last_push, _)] = pushes[..]
+                    && pushes.len() >= MIN_PUSHES
+                {
+                    let span = stmt.span.to(block.stmts[i + pushes.len()].span);
+                    let mutability = if is_mutated_after(cx, local_id, last_push.hir_id) {
+                        "mut "
+                    } else {
+                        ""
+                    };
+                    lint(cx, span, local, mutability, name, pushes.iter().map(|&(_, arg)| arg));
+                }
+            }
+        }
+    }
+}
+
+/// Matches `let mut s = String::new();`, returning the binding and its name.
+fn string_new_binding(cx: &LateContext<'_>, local: &LetStmt<'_>) -> Option<(HirId, Symbol)> {
+    if let PatKind::Binding(BindingMode::MUT, id, name, None) = local.pat.kind
+        && let Some(init) = local.init
+        && let ExprKind::Call(func, []) = init.kind
+        && is_expr_path_def_path(cx, func, &paths::STRING_NEW)
+        && !local.span.from_expansion()
+    {
+        Some((id, name.name))
+    } else {
+        None
+    }
+}
+
+/// Matches `s.push_str(arg);` on the given binding, returning the call and `arg`.
+fn push_str_arg<'tcx>(
+    cx: &LateContext<'tcx>,
+    stmt: &Stmt<'tcx>,
+    local_id: HirId,
+) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let StmtKind::Semi(expr) = stmt.kind
+        && let ExprKind::MethodCall(_, receiver, [arg], _) = expr.kind
+        && !stmt.span.from_expansion()
+        && path_to_local_id(receiver, local_id)
+        && let Some(method) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && match_def_path(cx, method, &paths::PUSH_STR)
+    {
+        Some((expr, arg))
+    } else {
+        None
+    }
+}
+
+fn lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    span: Span,
+    local: &LetStmt<'tcx>,
+    mutability: &str,
+    name: Symbol,
+    args: impl Iterator<Item = &'tcx Expr<'tcx>>,
+) {
+    // The concatenated literals, in case there is nothing to format
+    let mut literal = String::new();
+    let mut format_string = String::new();
+    let mut trailing_args = String::new();
+    let mut has_placeholders = false;
+    for arg in args {
+        if let ExprKind::Lit(lit) = arg.kind
+            && let LitKind::Str(_, StrStyle::Cooked) = lit.node
+            && let Some(snippet) = snippet_opt(cx, lit.span)
+            && let Some(contents) = snippet.strip_prefix('"').and_then(|s| s.strip_suffix('"'))
+        {
+            literal.push_str(contents);
+            format_string.push_str(&contents.replace('{', "{{").replace('}', "}}"));
+        } else if let Some(var) = inlinable_var(cx, arg) {
+            has_placeholders = true;
+            let _ = write!(format_string, "{{{var}}}");
+        } else {
+            has_placeholders = true;
+            format_string.push_str("{}");
+            let _ = write!(trailing_args, ", {}", snippet(cx, arg.span, ".."));
+        }
+    }
+
+    let ty = local
+        .ty
+        .map(|ty| format!(": {}", snippet(cx, ty.span, "_")))
+        .unwrap_or_default();
+
+    let (help, init) = if has_placeholders {
+        (
+            "consider using `format!`",
+            format!("format!(\"{format_string}\"{trailing_args})"),
+        )
+    } else {
+        ("consider using `String::from`", format!("String::from(\"{literal}\")"))
+    };
+    span_lint_and_sugg(
+        cx,
+        PUSH_STR_TO_FORMAT,
+        span,
+        "calls to `push_str` immediately after creating an empty `String`",
+        help,
+        format!("let {mutability}{name}{ty} = {init};"),
+        Applicability::MaybeIncorrect,
+    );
+}
+
+/// Returns the name of a local variable passed as `var` or `&var`, which can be inlined into the
+/// format string.
+fn inlinable_var<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'tcx>) -> Option<String> {
+    let arg = if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner) = arg.kind {
+        inner
+    } else {
+        arg
+    };
+    if let ExprKind::Path(_) = arg.kind
+        && path_to_local(arg).is_some()
+        && !arg.span.from_expansion()
+    {
+        snippet_opt(cx, arg.span)
+    } else {
+        None
+    }
+}
+
+/// Checks whether the binding is mutated or mutably borrowed after the given expression.
+fn is_mutated_after(cx: &LateContext<'_>, local_id: HirId, expr_id: HirId) -> bool {
+    for_each_local_use_after_expr(cx, local_id, expr_id, |e| {
+        let mutated = cx.typeck_results().expr_ty_adjusted(e).ref_mutability() == Some(Mutability::Mut)
+            || get_parent_expr(cx, e).is_some_and(|parent| match parent.kind {
+                ExprKind::AddrOf(_, Mutability::Mut, _) => true,
+                ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) => lhs.hir_id == e.hir_id,
+                _ => false,
+            });
+        if mutated {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_break()
+}
@@ -0,0 +1,96 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::is_from_proc_macro;
+use clippy_utils::source::{indent_of, snippet};
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir::FieldDef;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for struct fields of type `Rc<RefCell<_>>` or `Arc<Mutex<_>>` without a doc
+    /// comment.
+    ///
+    /// ### Why is this bad?
+    /// Shared interior mutability moves borrow checking to runtime: a `borrow_mut()` while
+    /// another borrow of the same `RefCell` is live panics, and locking a `Mutex` twice
+    /// deadlocks. The invariant preventing this should be written down next to the field.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// struct Node {
+    ///     children: Rc<RefCell<Vec<Node>>>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// struct Node {
+    ///     /// Only borrowed mutably by `Node::push`, which never calls back into other nodes.
+    ///     children: Rc<RefCell<Vec<Node>>>,
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub RC_REFCELL_UNDOCUMENTED,
+    restriction,
+    "`Rc<RefCell<_>>` or `Arc<Mutex<_>>` struct field without a doc comment"
+}
+
+declare_lint_pass!(RcRefCellUndocumented => [RC_REFCELL_UNDOCUMENTED]);
+
+impl<'tcx> LateLintPass<'tcx> for RcRefCellUndocumented {
+    fn check_field_def(&mut self, cx: &LateContext<'tcx>, field: &'tcx FieldDef<'tcx>) {
+        let ty = cx.tcx.type_of(field.def_id).instantiate_identity();
+        if let Some(desc) = shared_interior_mutability(cx, ty)
+            && !field.span.from_expansion()
+            && !cx
+                .tcx
+                .hir()
+                .attrs(field.hir_id)
+                .iter()
+                .any(|attr| attr.doc_str().is_some())
+            && !is_from_proc_macro(cx, field)
+        {
+            let msg = format!("`{desc}` field without a doc comment");
+            let help = "document the invariant which prevents a runtime borrow conflict";
+            if field.is_positional() {
+                span_lint_and_help(cx, RC_REFCELL_UNDOCUMENTED, field.span, msg, None, help);
+            } else {
+                let indent = " ".repeat(indent_of(cx, field.span).unwrap_or(0));
+                span_lint_and_sugg(
+                    cx,
+                    RC_REFCELL_UNDOCUMENTED,
+                    field.span,
+                    msg,
+                    help,
+                    format!(
+                        "/// Interior mutability: <explain why borrows of this field never overlap>\n{indent}{}",
+                        snippet(cx, field.span, "..")
+                    ),
+                    Applicability::HasPlaceholders,
+                );
+            }
+        }
+    }
+}
+
+/// Returns a description of the type if it's `Rc<RefCell<_>>` or `Arc<Mutex<_>>`.
+fn shared_interior_mutability<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<&'static str> {
+    let ty::Adt(_, args) = ty.kind() else {
+        return None;
+    };
+    let inner = args.types().next()?;
+    if is_type_diagnostic_item(cx, ty, sym::Rc) && is_type_diagnostic_item(cx, inner, sym::RefCell) {
+        Some("Rc<RefCell<_>>")
+    } else if is_type_diagnostic_item(cx, ty, sym::Arc) && is_type_diagnostic_item(cx, inner, sym::Mutex) {
+        Some("Arc<Mutex<_>>")
+    } else {
+        None
+    }
+}
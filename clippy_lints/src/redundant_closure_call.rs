@@ -1,12 +1,17 @@
 use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir};
 use clippy_utils::get_parent_expr;
+use clippy_utils::source::snippet_with_context;
 use clippy_utils::sugg::Sugg;
+use clippy_utils::visitors::for_each_expr_without_closures;
+use core::ops::ControlFlow;
 use hir::Param;
+use itertools::Itertools;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::intravisit::{Visitor as HirVisitor, Visitor};
 use rustc_hir::{
-    intravisit as hir_visit, ClosureKind, CoroutineDesugaring, CoroutineKind, CoroutineSource, ExprKind, Node,
+    intravisit as hir_visit, BlockCheckMode, CaptureBy, ClosureKind, CoroutineDesugaring, CoroutineKind,
+    CoroutineSource, ExprKind, Node, TyKind,
 };
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::hir::nested_filter;
@@ -33,6 +38,10 @@ declare_clippy_lint! {
     /// ```no_run
     /// let a = 42;
     /// ```
+    ///
+    /// A closure taking parameters can be replaced with a block binding the arguments, e.g.
+    /// `(|x| x + 1)(2)` with `{ let x = 2; x + 1 }`, unless it is a `move` closure or captures
+    /// variables.
     #[clippy::version = "pre 1.29.0"]
     pub REDUNDANT_CLOSURE_CALL,
     complexity,
@@ -124,6 +133,81 @@ fn find_innermost_closure<'tcx>(
     data
 }
 
+/// Builds a block binding the arguments of the call to the closure's parameters, followed by its
+/// body, e.g. `{ let x = 2; x + 1 }` for `(|x| x + 1)(2)`. A `move` closure or one capturing
+/// variables may behave differently from a block, in which case `None` is returned.
+fn block_with_params<'tcx>(
+    cx: &LateContext<'tcx>,
+    call: &'tcx hir::Expr<'tcx>,
+    closure_expr: &'tcx hir::Expr<'tcx>,
+    body: &'tcx hir::Expr<'tcx>,
+    params: &'tcx [Param<'tcx>],
+) -> Option<String> {
+    let ExprKind::Closure(closure) = closure_expr.kind else {
+        return None;
+    };
+    let ExprKind::Call(_, args) = call.kind else {
+        return None;
+    };
+    if closure.capture_clause != CaptureBy::Ref
+        || cx.tcx.upvars_mentioned(closure.def_id).is_some()
+        || args.len() != params.len()
+    {
+        return None;
+    }
+    // `return`, `?` and labeled `break`s would leave the enclosing function or block instead
+    if for_each_expr_without_closures(body, |e| match e.kind {
+        ExprKind::Ret(_)
+        | ExprKind::Match(.., hir::MatchSource::TryDesugar(_))
+        | ExprKind::Break(hir::Destination { label: Some(_), .. }, _) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+    {
+        return None;
+    }
+
+    let ctxt = call.span.ctxt();
+    let mut applicability = Applicability::MaybeIncorrect;
+    let mut pats = Vec::with_capacity(params.len());
+    let mut tys = Vec::with_capacity(params.len());
+    let mut arg_snips = Vec::with_capacity(params.len());
+    for ((param, arg), input) in params.iter().zip(args).zip(closure.fn_decl.inputs) {
+        pats.push(snippet_with_context(cx, param.pat.span, ctxt, "..", &mut applicability).0);
+        arg_snips.push(snippet_with_context(cx, arg.span, ctxt, "..", &mut applicability).0);
+        tys.push(if let TyKind::Infer = input.kind {
+            None
+        } else {
+            Some(snippet_with_context(cx, input.span, ctxt, "..", &mut applicability).0)
+        });
+    }
+    // All parameters are bound at once, as an argument may mention a parameter of the same name,
+    // e.g. `(|x, y| x - y)(y, x)`
+    let lets = match (&pats[..], &tys[..], &arg_snips[..]) {
+        ([], ..) => String::new(),
+        ([pat], [None], [arg]) => format!("let {pat} = {arg};"),
+        ([pat], [Some(ty)], [arg]) => format!("let {pat}: {ty} = {arg};"),
+        _ => {
+            let ty = if tys.iter().all(Option::is_none) {
+                String::new()
+            } else {
+                format!(": ({})", tys.iter().map(|ty| ty.as_deref().unwrap_or("_")).join(", "))
+            };
+            format!("let ({}){ty} = ({});", pats.join(", "), arg_snips.join(", "))
+        },
+    };
+    let (body_snip, _) = snippet_with_context(cx, body.span, ctxt, "..", &mut applicability);
+    // The statements of a block body are moved into the new block
+    if let ExprKind::Block(block, None) = body.kind
+        && let BlockCheckMode::DefaultBlock = block.rules
+        && let Some(inner) = body_snip.strip_prefix('{').and_then(|snip| snip.strip_suffix('}'))
+    {
+        Some(format!("{{ {lets}{inner}}}"))
+    } else {
+        Some(format!("{{ {lets} {body_snip} }}"))
+    }
+}
+
 /// "Walks up" the chain of calls to find the outermost call expression, and returns the depth:
 /// ```rust,ignore
 /// (|| || || 3)()()()
@@ -220,6 +304,16 @@ impl<'tcx> LateLintPass<'tcx> for RedundantClosureCall {
                         };
 
                         diag.span_suggestion(full_expr.span, "try doing something like", hint, applicability);
+                    } else if call_depth == 1
+                        && !coroutine_kind.is_async()
+                        && let Some(hint) = block_with_params(cx, expr, recv, body, params)
+                    {
+                        diag.span_suggestion(
+                            full_expr.span,
+                            "try doing something like",
+                            hint,
+                            Applicability::MaybeIncorrect,
+                        );
                     }
                 },
             );
@@ -1,13 +1,19 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::{indent_of, reindent_multiline, snippet_opt};
 use rustc_ast::ast::{Block, Expr, ExprKind, Stmt, StmtKind};
 use rustc_ast::visit::{walk_expr, Visitor};
+use rustc_errors::Applicability;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_session::declare_lint_pass;
+use rustc_span::hygiene::{ExpnKind, MacroKind};
+use rustc_span::{BytePos, Span};
+use std::borrow::Cow;
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for `else` blocks that can be removed without changing semantics.
+    /// Checks for `else` blocks that can be removed without changing semantics, because the `if`
+    /// block always ends with `return`, `break`, `continue` or a panicking macro like `panic!()`.
     ///
     /// ### Why is this bad?
     /// The `else` block adds unnecessary indentation and verbosity.
@@ -77,17 +83,59 @@ impl EarlyLintPass for RedundantElse {
                 _ => break,
             }
         }
-        span_lint_and_help(
+        let ExprKind::Block(els_block, _) = &els.kind else {
+            return;
+        };
+        // Moving the contents out changes the scope of any binding introduced in the `else` block.
+        let mut applicability = if els_block.stmts.iter().any(|stmt| matches!(stmt.kind, StmtKind::Let(_))) {
+            Applicability::MaybeIncorrect
+        } else {
+            Applicability::MachineApplicable
+        };
+        let Some(sugg) = outdented_contents(cx, expr.span, els.span, &mut applicability) else {
+            return;
+        };
+        span_lint_and_sugg(
             cx,
             REDUNDANT_ELSE,
-            els.span,
+            then.span.shrink_to_hi().to(els.span),
             "redundant else block",
-            None,
             "remove the `else` block and move the contents out",
+            sugg,
+            applicability,
         );
     }
 }
 
+/// Returns the contents of the `else` block reindented to the level of the `if` statement, ready
+/// to replace everything from the end of the `if` block up to the end of the `else` block.
+fn outdented_contents(
+    cx: &EarlyContext<'_>,
+    if_span: Span,
+    els_span: Span,
+    applicability: &mut Applicability,
+) -> Option<String> {
+    let snip = snippet_opt(
+        cx,
+        els_span
+            .with_lo(els_span.lo() + BytePos(1))
+            .with_hi(els_span.hi() - BytePos(1)),
+    )?;
+    let contents = snip.trim();
+    if contents.is_empty() {
+        return Some(String::new());
+    }
+    let Some(indent) = indent_of(cx, if_span) else {
+        *applicability = Applicability::MaybeIncorrect;
+        return Some(format!("\n{contents}"));
+    };
+    Some(format!(
+        "\n{}{}",
+        " ".repeat(indent),
+        reindent_multiline(Cow::Borrowed(contents), true, Some(indent))
+    ))
+}
+
 /// Call `check` functions to check if an expression always breaks control flow
 #[derive(Default)]
 struct BreakVisitor {
@@ -103,6 +151,10 @@ impl<'ast> Visitor<'ast> for BreakVisitor {
     }
 
     fn visit_expr(&mut self, expr: &'ast Expr) {
+        if is_panic_like_macro(expr.span) {
+            self.is_break = true;
+            return;
+        }
         self.is_break = match expr.kind {
             ExprKind::Break(..) | ExprKind::Continue(..) | ExprKind::Ret(..) => true,
             ExprKind::Match(_, ref arms, _) => arms.iter().all(|arm|
@@ -111,7 +163,9 @@ impl<'ast> Visitor<'ast> for BreakVisitor {
             ExprKind::If(_, ref then, Some(ref els)) => self.check_block(then) && self.check_expr(els),
             ExprKind::If(_, _, None)
             // ignore loops for simplicity
-            | ExprKind::While(..) | ExprKind::ForLoop { .. } | ExprKind::Loop(..) => false,
+            | ExprKind::While(..) | ExprKind::ForLoop { .. } | ExprKind::Loop(..)
+            // a closure body does not affect the control flow of the enclosing block
+            | ExprKind::Closure(..) => false,
             _ => {
                 walk_expr(self, expr);
                 return;
@@ -120,6 +174,17 @@ impl<'ast> Visitor<'ast> for BreakVisitor {
     }
 }
 
+/// Checks if the span comes from the expansion of one of the standard macros that always panic.
+fn is_panic_like_macro(span: Span) -> bool {
+    span.macro_backtrace().last().is_some_and(|expn_data| {
+        matches!(
+            expn_data.kind,
+            ExpnKind::Macro(MacroKind::Bang, name)
+                if matches!(name.as_str(), "panic" | "unreachable" | "todo" | "unimplemented")
+        )
+    })
+}
+
 impl BreakVisitor {
     fn check<T>(&mut self, item: T, visit: fn(&mut Self, T)) -> bool {
         visit(self, item);
@@ -0,0 +1,101 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{higher, is_res_lang_ctor};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, LangItem, Node, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if let Ok(..) = ..` and `if let Some(..) = ..` statements without an `else`
+    /// branch, outside of loops and closures.
+    ///
+    /// ### Why is this bad?
+    /// The `Err` or `None` case is silently ignored. An `else` branch, even an empty one, shows
+    /// that ignoring it is intended.
+    ///
+    /// Inside a loop or a closure, e.g. one passed to an iterator adapter, skipping the failures
+    /// is common, so these are not linted.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # fn save(_: &str) -> std::io::Result<usize> { Ok(0) }
+    /// if let Ok(written) = save("data") {
+    ///     println!("wrote {written} bytes");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn save(_: &str) -> std::io::Result<usize> { Ok(0) }
+    /// match save("data") {
+    ///     Ok(written) => println!("wrote {written} bytes"),
+    ///     Err(e) => eprintln!("failed to save: {e}"),
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub SILENT_RESULT_DISCARD,
+    restriction,
+    "`if let Ok(..)` or `if let Some(..)` without an `else` branch, silently ignoring the failure"
+}
+
+declare_lint_pass!(SilentResultDiscard => [SILENT_RESULT_DISCARD]);
+
+impl<'tcx> LateLintPass<'tcx> for SilentResultDiscard {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let Some(higher::IfLet {
+            let_pat,
+            if_then,
+            if_else: None,
+            ..
+        }) = higher::IfLet::hir(cx, expr)
+            && !expr.span.from_expansion()
+            && let PatKind::TupleStruct(ref qpath, [_], _) = let_pat.kind
+            && let res = cx.qpath_res(qpath, let_pat.hir_id)
+            && let Some(failure) = if is_res_lang_ctor(cx, res, LangItem::ResultOk) {
+                Some("Err")
+            } else if is_res_lang_ctor(cx, res, LangItem::OptionSome) {
+                Some("None")
+            } else {
+                None
+            }
+            && is_statement(cx, expr)
+            && !is_in_loop_or_closure(cx, expr)
+        {
+            span_lint_and_then(
+                cx,
+                SILENT_RESULT_DISCARD,
+                expr.span,
+                format!("this `if let` silently ignores the `{failure}` case"),
+                |diag| {
+                    diag.span_suggestion(
+                        if_then.span.shrink_to_hi(),
+                        format!("handle the `{failure}` case in an `else` branch"),
+                        format!(" else {{ /* handle the `{failure}` case */ }}"),
+                        Applicability::HasPlaceholders,
+                    );
+                    diag.help("or use a `match` to handle both cases");
+                },
+            );
+        }
+    }
+}
+
+/// Whether the expression is a statement, or the final expression of a block which isn't used.
+fn is_statement(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match cx.tcx.parent_hir_node(expr.hir_id) {
+        Node::Stmt(_) => true,
+        Node::Block(block) => cx.typeck_results().expr_ty(expr).is_unit() && block.expr.is_some(),
+        _ => false,
+    }
+}
+
+fn is_in_loop_or_closure(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(e) if matches!(e.kind, ExprKind::Loop(..) | ExprKind::Closure(_)) => return true,
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => return false,
+            _ => {},
+        }
+    }
+    false
+}
@@ -0,0 +1,105 @@
+use clippy_utils::consts::{constant_simple, Constant};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{match_def_path, path_def_id, paths};
+use rustc_errors::Applicability;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `std::thread::sleep` with a zero `Duration`, such as `Duration::ZERO`,
+    /// `Duration::from_millis(0)` or `Duration::new(0, 0)`.
+    ///
+    /// ### Why is this bad?
+    /// Sleeping for no time at all has no observable effect. The call is most likely a leftover
+    /// from debugging, or the duration is not computed the way it was intended to.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # fn work() {}
+    /// work();
+    /// std::thread::sleep(Duration::from_secs(0));
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # fn work() {}
+    /// work();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub SLEEP_ZERO_DURATION,
+    style,
+    "calling `std::thread::sleep` with a zero duration"
+}
+
+declare_lint_pass!(SleepZeroDuration => [SLEEP_ZERO_DURATION]);
+
+impl<'tcx> LateLintPass<'tcx> for SleepZeroDuration {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(def_id) = path_def_id(cx, func)
+            && match_def_path(cx, def_id, &paths::THREAD_SLEEP)
+            && is_zero_duration(cx, arg)
+        {
+            let (span, sugg) = if let Node::Stmt(stmt) = cx.tcx.parent_hir_node(expr.hir_id) {
+                (stmt.span, String::new())
+            } else {
+                (expr.span, "()".to_owned())
+            };
+            span_lint_and_sugg(
+                cx,
+                SLEEP_ZERO_DURATION,
+                span,
+                "sleeping for a zero duration has no effect",
+                "remove the call",
+                sugg,
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+fn is_zero_duration(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if expr.span.from_expansion() {
+        return false;
+    }
+    let is_zero = |arg: &Expr<'_>| match constant_simple(cx, cx.typeck_results(), arg) {
+        Some(Constant::Int(value)) => value == 0,
+        Some(Constant::F32(value)) => value == 0.0,
+        Some(Constant::F64(value)) => value == 0.0,
+        _ => false,
+    };
+    match expr.kind {
+        ExprKind::Path(_) => path_def_id(cx, expr).is_some_and(|def_id| {
+            cx.tcx.def_kind(def_id) == DefKind::AssocConst
+                && is_duration_item(cx, def_id)
+                && cx.tcx.item_name(def_id).as_str() == "ZERO"
+        }),
+        ExprKind::Call(func, args) => path_def_id(cx, func).is_some_and(|def_id| {
+            is_duration_item(cx, def_id)
+                && match (cx.tcx.item_name(def_id).as_str(), args) {
+                    (
+                        "from_secs" | "from_millis" | "from_micros" | "from_nanos" | "from_secs_f32" | "from_secs_f64",
+                        [arg],
+                    ) => is_zero(arg),
+                    ("new", [secs, nanos]) => is_zero(secs) && is_zero(nanos),
+                    _ => false,
+                }
+        }),
+        _ => false,
+    }
+}
+
+/// Whether the item is defined in an inherent impl of `Duration`.
+fn is_duration_item(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    cx.tcx.impl_of_method(def_id).is_some_and(|impl_id| {
+        cx.tcx.trait_id_of_impl(impl_id).is_none()
+            && is_type_diagnostic_item(cx, cx.tcx.type_of(impl_id).instantiate_identity(), sym::Duration)
+    })
+}
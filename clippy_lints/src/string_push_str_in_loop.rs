@@ -0,0 +1,155 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{get_parent_expr, higher, is_expr_path_def_path, path_to_local_id, paths};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingMode, Block, Expr, ExprKind, HirId, LoopSource, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::declare_lint_pass;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a `String` created with `String::new()` which is then grown by `push_str` or
+    /// `push` calls on every iteration of a loop.
+    ///
+    /// ### Why restrict this?
+    /// The string is reallocated every time it outgrows its capacity. If the final length can be
+    /// estimated, allocating it upfront with `String::with_capacity` avoids this.
+    ///
+    /// The lint can't tell whether the length is known upfront, which it often isn't, e.g. when
+    /// the pushed strings are computed inside the loop.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # let names = ["a", "b"];
+    /// let mut s = String::new();
+    /// for name in names {
+    ///     s.push_str(name);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let names = ["a", "b"];
+    /// let mut s = String::with_capacity(names.iter().map(|name| name.len()).sum());
+    /// for name in names {
+    ///     s.push_str(name);
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub STRING_PUSH_STR_IN_LOOP,
+    restriction,
+    "`String::new()` grown by `push_str` calls in a loop, instead of `String::with_capacity`"
+}
+
+declare_lint_pass!(StringPushStrInLoop => [STRING_PUSH_STR_IN_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for StringPushStrInLoop {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Let(local) = stmt.kind
+                && let PatKind::Binding(BindingMode::MUT, local_id, _, None) = local.pat.kind
+                && let Some(init) = local.init
+                && let ExprKind::Call(func, []) = init.kind
+                && is_expr_path_def_path(cx, func, &paths::STRING_NEW)
+                && !local.span.from_expansion()
+                && !is_reserved(cx, local_id, init)
+                && let Some(push_span) = push_in_loop(block, i + 1, local_id)
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    STRING_PUSH_STR_IN_LOOP,
+                    local.hir_id,
+                    local.span,
+                    "this `String` is grown inside a loop without allocating its capacity upfront",
+                    |diag| {
+                        diag.span_note(push_span, "the string is grown here");
+                        diag.span_suggestion(
+                            init.span,
+                            "consider using `String::with_capacity`",
+                            "String::with_capacity(/* expected length */)",
+                            Applicability::HasPlaceholders,
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the method name if the expression calls a method on the binding.
+fn method_on_local<'tcx>(expr: &'tcx Expr<'tcx>, local_id: HirId) -> Option<&'tcx str> {
+    if let ExprKind::MethodCall(segment, receiver, ..) = expr.kind
+        && path_to_local_id(receiver, local_id)
+    {
+        Some(segment.ident.as_str())
+    } else {
+        None
+    }
+}
+
+/// Checks whether capacity is already reserved through `reserve` or `reserve_exact`.
+fn is_reserved(cx: &LateContext<'_>, local_id: HirId, init: &Expr<'_>) -> bool {
+    for_each_local_use_after_expr(cx, local_id, init.hir_id, |e| {
+        if get_parent_expr(cx, e)
+            .is_some_and(|parent| matches!(method_on_local(parent, local_id), Some("reserve" | "reserve_exact")))
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_break()
+}
+
+/// Finds a `push_str` or `push` call on the binding running on every iteration of a loop following
+/// the binding, i.e. a statement of the loop body, and returns its span. Pushes under a condition
+/// are ignored, as the final length can't be estimated from the number of iterations.
+fn push_in_loop<'tcx>(block: &'tcx Block<'tcx>, start: usize, local_id: HirId) -> Option<Span> {
+    block.stmts[start..]
+        .iter()
+        .filter_map(stmt_expr)
+        .chain(block.expr)
+        .find_map(|e| loop_body(e).and_then(|body| unconditional_push(body, local_id)))
+}
+
+fn unconditional_push<'tcx>(block: &'tcx Block<'tcx>, local_id: HirId) -> Option<Span> {
+    block
+        .stmts
+        .iter()
+        .filter_map(stmt_expr)
+        .chain(block.expr)
+        .find_map(|e| {
+            if matches!(method_on_local(e, local_id), Some("push_str" | "push")) && !e.span.from_expansion() {
+                Some(e.span)
+            } else {
+                loop_body(e).and_then(|body| unconditional_push(body, local_id))
+            }
+        })
+}
+
+/// Returns the body of a `for`, `while` or `loop` loop.
+fn loop_body<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Block<'tcx>> {
+    let body = if let Some(for_loop) = higher::ForLoop::hir(expr) {
+        for_loop.body
+    } else if let Some(while_loop) = higher::While::hir(expr) {
+        while_loop.body
+    } else if let ExprKind::Loop(block, _, LoopSource::Loop, _) = expr.kind {
+        return Some(block);
+    } else {
+        return None;
+    };
+    if let ExprKind::Block(block, _) = body.kind {
+        Some(block)
+    } else {
+        None
+    }
+}
+
+fn stmt_expr<'tcx>(stmt: &'tcx Stmt<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let StmtKind::Expr(e) | StmtKind::Semi(e) = stmt.kind {
+        Some(e)
+    } else {
+        None
+    }
+}
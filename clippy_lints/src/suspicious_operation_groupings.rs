@@ -577,7 +577,7 @@ fn ident_difference_expr_with_base_location(
 
     let mut difference = IdentDifference::NoDifference;
 
-    for (left_attr, right_attr) in left.attrs.iter().zip(right.attrs.iter()) {
+    for (left_attr, right_attr) in left.attrs.iter().zip(&right.attrs) {
         let (new_difference, new_base) =
             ident_difference_via_ident_iter_with_base_location(left_attr, right_attr, base);
         base = new_base;
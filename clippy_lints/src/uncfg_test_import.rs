@@ -0,0 +1,188 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::source::{indent_of, snippet_opt};
+use clippy_utils::{is_cfg_test, is_in_cfg_test};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{LocalDefId, LocalModDefId};
+use rustc_hir::intravisit::{walk_path, Visitor};
+use rustc_hir::{HirId, Item, ItemKind, Node, Path, UseKind, UsePath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::ty::Visibility;
+use rustc_session::declare_lint_pass;
+use rustc_span::symbol::kw;
+use rustc_span::Symbol;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for private module level `use` items which are only referred to from
+    /// `#[cfg(test)]` items.
+    ///
+    /// ### Why is this bad?
+    /// The import is only needed when compiling the tests, but is part of every build. Outside of
+    /// tests it is reported as unused, and it hides which dependencies the tests need.
+    ///
+    /// ### Known problems
+    /// The lint only fires when compiling the tests, since `#[cfg(test)]` items are not part of
+    /// other builds. Traits and macros are not checked, as their uses cannot be reliably tracked.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use std::collections::HashSet;
+    ///
+    /// pub fn double(x: u32) -> u32 {
+    ///     x * 2
+    /// }
+    ///
+    /// #[cfg(test)]
+    /// mod tests {
+    ///     use super::*;
+    ///
+    ///     #[test]
+    ///     fn unique() {
+    ///         let values: HashSet<_> = [1, 2].map(double).into();
+    ///         assert_eq!(values.len(), 2);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// pub fn double(x: u32) -> u32 {
+    ///     x * 2
+    /// }
+    ///
+    /// #[cfg(test)]
+    /// mod tests {
+    ///     use super::*;
+    ///     use std::collections::HashSet;
+    ///
+    ///     #[test]
+    ///     fn unique() {
+    ///         let values: HashSet<_> = [1, 2].map(double).into();
+    ///         assert_eq!(values.len(), 2);
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub UNCFG_TEST_IMPORT,
+    pedantic,
+    "imports only used in tests which are not gated behind `#[cfg(test)]`"
+}
+
+declare_lint_pass!(UncfgTestImport => [UNCFG_TEST_IMPORT]);
+
+impl<'tcx> LateLintPass<'tcx> for UncfgTestImport {
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let mut visitor = PathVisitor {
+            cx,
+            uses: FxHashMap::default(),
+        };
+        cx.tcx.hir().walk_toplevel_module(&mut visitor);
+
+        for item_id in cx.tcx.hir().items() {
+            let item = cx.tcx.hir().item(item_id);
+            if let ItemKind::Use(path, UseKind::Single) = item.kind
+                && item.ident.name != kw::Underscore
+                && !item.span.from_expansion()
+                && is_private_import(cx, item.owner_id.def_id)
+                && matches!(
+                    cx.tcx.parent_hir_node(item.hir_id()),
+                    Node::Item(Item {
+                        kind: ItemKind::Mod(..),
+                        ..
+                    }) | Node::Crate(..)
+                )
+                && !is_cfg_test(cx.tcx, item.hir_id())
+                && !is_in_cfg_test(cx.tcx, item.hir_id())
+                && is_trackable(path)
+            {
+                let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+                let mut uses = path
+                    .res
+                    .iter()
+                    .filter_map(|&res| visitor.uses.get(&(item.ident.name, res)))
+                    .flatten()
+                    .filter(|(use_module, _)| cx.tcx.is_descendant_of(use_module.to_def_id(), module.to_def_id()))
+                    .peekable();
+
+                // Imports without any use are already reported by `unused_imports`
+                if uses.peek().is_some() && uses.all(|&(_, in_test)| in_test) {
+                    span_lint_hir_and_then(
+                        cx,
+                        UNCFG_TEST_IMPORT,
+                        item.hir_id(),
+                        item.span,
+                        format!("`{}` is only used in tests", item.ident),
+                        |diag| {
+                            // Imports which are part of a group only span their own path
+                            if let Some(snippet) = snippet_opt(cx, item.span)
+                                && snippet.starts_with("use ")
+                            {
+                                diag.span_suggestion_verbose(
+                                    item.span.shrink_to_lo(),
+                                    "only import it when compiling the tests",
+                                    format!("#[cfg(test)]\n{}", " ".repeat(indent_of(cx, item.span).unwrap_or(0))),
+                                    Applicability::MaybeIncorrect,
+                                );
+                            } else {
+                                diag.help(
+                                    "gate the import behind `#[cfg(test)]`, or move it into the module containing the tests",
+                                );
+                            }
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Whether the import is only visible in its own module. Re-exports are part of the module's
+/// interface, regardless of where they are used.
+fn is_private_import(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    cx.tcx.visibility(def_id) == Visibility::Restricted(cx.tcx.parent_module_from_def_id(def_id).to_def_id())
+}
+
+/// Traits are used through method calls and macros are expanded before their uses can be seen,
+/// neither of which show up as a path referring to the import.
+fn is_trackable(path: &UsePath<'_>) -> bool {
+    path.res.iter().all(|res| {
+        !matches!(
+            res,
+            Res::Err | Res::Def(DefKind::Trait | DefKind::TraitAlias | DefKind::Macro(_), _)
+        )
+    })
+}
+
+/// Collects the paths referring to an item by the name their first segment is imported as,
+/// along with the module containing them and whether they are part of a test.
+struct PathVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    uses: FxHashMap<(Symbol, Res), Vec<(LocalModDefId, bool)>>,
+}
+
+impl<'tcx> Visitor<'tcx> for PathVisitor<'_, 'tcx> {
+    type NestedFilter = nested_filter::All;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+
+    // Imports re-importing an item don't use it in the importing module
+    fn visit_use(&mut self, _: &'tcx UsePath<'tcx>, _: HirId) {}
+
+    fn visit_path(&mut self, path: &Path<'tcx>, id: HirId) {
+        if let [first, ..] = path.segments {
+            // Paths coming from macros are assumed to be needed outside of tests. Test functions
+            // which aren't gated behind `#[cfg(test)]` are part of a test crate, i.e. all its
+            // imports are only used in tests anyway.
+            let in_test = !path.span.from_expansion() && is_in_cfg_test(self.cx.tcx, id);
+            self.uses
+                .entry((first.ident.name, first.res))
+                .or_default()
+                .push((self.cx.tcx.parent_module(id), in_test));
+        }
+        walk_path(self, path);
+    }
+}
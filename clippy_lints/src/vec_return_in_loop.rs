@@ -0,0 +1,152 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{fn_def_id, get_parent_expr, higher};
+use core::ops::ControlFlow;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, HirId, LoopSource, MatchSource, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for bindings inside a loop body initialized by a call to a local function returning
+    /// a `Vec`, where the binding is only used to be iterated over, or to extend another
+    /// collection.
+    ///
+    /// ### Why is this bad?
+    /// A new `Vec` is allocated on every iteration only to be consumed right away. Returning an
+    /// iterator from the function avoids the allocations, and collecting once outside the loop
+    /// avoids all but one.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// fn neighbours(i: u32) -> Vec<u32> {
+    ///     vec![i - 1, i + 1]
+    /// }
+    ///
+    /// let mut all = Vec::new();
+    /// for i in 1..10 {
+    ///     let n = neighbours(i);
+    ///     all.extend(n);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// fn neighbours(i: u32) -> impl Iterator<Item = u32> {
+    ///     [i - 1, i + 1].into_iter()
+    /// }
+    ///
+    /// let mut all = Vec::new();
+    /// for i in 1..10 {
+    ///     let n = neighbours(i);
+    ///     all.extend(n);
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub VEC_RETURN_IN_LOOP,
+    pedantic,
+    "`Vec` returned by a function inside a loop, only to be iterated over"
+}
+
+declare_lint_pass!(VecReturnInLoop => [VEC_RETURN_IN_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for VecReturnInLoop {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let Some(block) = loop_body(expr) else {
+            return;
+        };
+        for stmt in block.stmts {
+            if let StmtKind::Let(local) = stmt.kind
+                && let PatKind::Binding(_, local_id, _, None) = local.pat.kind
+                && let Some(init) = local.init
+                && let ExprKind::Call(..) | ExprKind::MethodCall(..) = init.kind
+                && !local.span.from_expansion()
+                && let Some(callee) = fn_def_id(cx, init).and_then(DefId::as_local)
+                && let init_ty = cx.typeck_results().expr_ty(init)
+                && is_type_diagnostic_item(cx, init_ty, sym::Vec)
+                && let ty::Adt(_, args) = init_ty.kind()
+                && is_only_iterated(cx, local_id, init)
+            {
+                let fn_name = cx.tcx.item_name(callee.to_def_id());
+                span_lint_and_then(
+                    cx,
+                    VEC_RETURN_IN_LOOP,
+                    local.span,
+                    "a `Vec` is allocated on every iteration of the loop, only to be iterated over",
+                    |diag| {
+                        diag.span_note(cx.tcx.def_span(callee), format!("`{fn_name}` is defined here"));
+                        diag.help(format!(
+                            "consider returning `impl Iterator<Item = {}>` from `{fn_name}`, or collecting the items outside of the loop",
+                            args.type_at(0)
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the body of a `for`, `while`, `while let` or `loop` loop.
+fn loop_body<'tcx>(expr: &'tcx Expr<'tcx>) -> Option<&'tcx Block<'tcx>> {
+    let body = if let Some(for_loop) = higher::ForLoop::hir(expr) {
+        for_loop.body
+    } else if let Some(while_loop) = higher::While::hir(expr) {
+        while_loop.body
+    } else if let Some(while_let) = higher::WhileLet::hir(expr) {
+        while_let.if_then
+    } else if let ExprKind::Loop(block, _, LoopSource::Loop, _) = expr.kind {
+        return Some(block);
+    } else {
+        return None;
+    };
+    if let ExprKind::Block(block, _) = body.kind {
+        Some(block)
+    } else {
+        None
+    }
+}
+
+/// Checks that the binding is used exactly once after its initializer, either as the iterator of
+/// a `for` loop, possibly through `iter` or `into_iter`, or as the argument of `extend`.
+fn is_only_iterated(cx: &LateContext<'_>, local_id: HirId, init: &Expr<'_>) -> bool {
+    let mut uses = 0;
+    let res = for_each_local_use_after_expr(cx, local_id, init.hir_id, |e| {
+        uses += 1;
+        if uses == 1 && is_iterated(cx, e) {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    });
+    res.is_continue() && uses == 1
+}
+
+/// Checks whether the use of the binding, possibly borrowed or turned into an iterator, e.g. `&v`
+/// or `v.iter()`, is iterated over by a `for` loop or passed to `extend`.
+fn is_iterated<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> bool {
+    while let Some(parent) = get_parent_expr(cx, expr) {
+        match parent.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, ..) => expr = parent,
+            ExprKind::MethodCall(segment, receiver, [], _)
+                if receiver.hir_id == expr.hir_id
+                    && matches!(segment.ident.as_str(), "iter" | "into_iter" | "iter_mut") =>
+            {
+                expr = parent;
+            },
+            ExprKind::MethodCall(segment, _, [arg], _) => {
+                return arg.hir_id == expr.hir_id && segment.ident.as_str() == "extend";
+            },
+            // The desugaring of a `for` loop passes the iterator to `IntoIterator::into_iter`
+            ExprKind::Call(_, [arg]) => {
+                return arg.hir_id == expr.hir_id
+                    && get_parent_expr(cx, parent)
+                        .is_some_and(|e| matches!(e.kind, ExprKind::Match(_, _, MatchSource::ForLoopDesugar)));
+            },
+            _ => return false,
+        }
+    }
+    false
+}
@@ -1,17 +1,39 @@
-use rustc_lint::{LateContext, LateLintPass};
-use rustc_data_structures::fx::FxHashSet;
-use rustc_ast::token::{Token, TokenKind};
+use clippy_config::Conf;
+use clippy_utils::attrs::get_attr;
+use clippy_utils::diagnostics::{span_lint_hir_and_then, span_lint_hir_and_then_as_error};
+use clippy_utils::source::snippet_opt;
+use clippy_utils::visitors::{for_each_expr, for_each_expr_without_closures};
+use clippy_utils::{fn_def_id, is_in_test, is_lint_allowed, match_def_path, path_def_id, path_res, paths};
+use itertools::Itertools;
+use rustc_ast::token::{IdentIsRaw, LitKind, Token, TokenKind};
 use rustc_ast::tokenstream::TokenTree;
-use rustc_session::impl_lint_pass;
-use rustc_ast::{AttrArgs, AttrKind};
+use rustc_ast::{AttrArgs, AttrKind, Attribute};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap, FxIndexSet};
+use rustc_errors::{Applicability, Diag, MultiSpan};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{self, FnKind, Visitor};
 use rustc_hir::{
-    intravisit,
-    intravisit::Visitor,
-    HirId, ItemKind};
+    Body, Expr, ExprKind, FieldDef, FnDecl, HirId, ImplItem, ImplItemKind, Item, ItemKind, Mutability, Node, Pat,
+    PatKind, Stmt, StmtKind, UnOp,
+};
+use rustc_hir_typeck::expr_use_visitor::{Delegate, ExprUseVisitor, PlaceWithHirId};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::hir::place::ProjectionKind;
+use rustc_middle::mir::FakeReadCause;
+use rustc_middle::ty::print::with_forced_trimmed_paths;
+use rustc_middle::ty::{self, AssocKind, BorrowKind, Ty, TyCtxt};
+use rustc_session::{impl_lint_pass, Session};
+use rustc_span::symbol::kw;
+use rustc_span::{sym, BytePos, Span, Symbol};
+use rustc_target::abi::FieldIdx;
+use std::borrow::Cow;
+use std::ops::ControlFlow;
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks if a struct field is mutated only by functions specified in the `#[mutatedby(...)]` attribute.
+    /// Checks if a struct field is mutated only by functions specified in the
+    /// `#[clippy::mutatedby(...)]` attribute.
     ///
     /// ### Why restrict this?
     /// To ensure that certain fields are only modified by specific functions to maintain encapsulation and control over field mutations.
@@ -19,7 +41,7 @@ declare_clippy_lint! {
     /// ### Example
     /// ```rust
     /// pub struct MyStruct {
-    ///     #[mutatedby("allowed_function")]
+    ///     #[clippy::mutatedby("allowed_function")]
     ///     field1: u8,
     /// }
     ///
@@ -33,84 +55,1584 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    ///
+    /// Mutations are attributed to the function whose body contains them, so free functions
+    /// mutating the field through a parameter or a local of the struct type are checked as well.
+    /// Calls to `RefCell::borrow_mut`, `Cell::set` and `Cell::replace` on the field count as
+    /// mutations, even though they only need a shared reference. Assigning through an overloaded
+    /// `IndexMut`, as in `self.field[key] = value`, mutates the field as well, and so does taking a
+    /// raw mutable pointer to it with `addr_of_mut!(self.field)`.
+    /// Constructing a new value is not a mutation, even with functional update syntax, e.g.
+    /// `Self { field1: 1, ..*self }`, which only reads the remaining fields of the base.
+    ///
+    /// The permission can also be granted from the function side with `#[clippy::may_mutate(...)]`,
+    /// listing fields of the `Self` type. A function declared this way is expected to actually
+    /// mutate every field it lists.
+    /// ```rust
+    /// pub struct MyStruct {
+    ///     #[clippy::mutatedby("allowed_function")]
+    ///     field1: u8,
+    /// }
+    ///
+    /// impl MyStruct {
+    ///     fn allowed_function(&mut self) {
+    ///         self.field1 = 10;
+    ///     }
+    ///
+    ///     #[clippy::may_mutate("field1")]
+    ///     fn other_allowed_function(&mut self) {
+    ///         self.field1 = 20;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Entries may be qualified with the path leading to the function, e.g. `"MyStruct::set"`, and
+    /// may use `*` and `?` as wildcards within a path segment, e.g. `"set_*"`. Unqualified entries
+    /// match functions of that name anywhere, while entries starting with `crate::` are anchored at
+    /// the crate root. A final `*` segment matches the rest of the path, so `"crate::setters::*"`
+    /// allows every function of the `setters` module and its submodules. Methods of trait impls are
+    /// qualified by their `Self` type as well, so `"MyStruct::fmt"` matches the `fmt` method of
+    /// `impl Display for MyStruct`.
+    ///
+    /// An `impl = "MyStruct"` entry allows every function of an impl block of `MyStruct`, including
+    /// trait impls, to mutate the field.
+    /// ```rust
+    /// pub struct MyStruct {
+    ///     #[clippy::mutatedby(impl = "MyStruct")]
+    ///     field1: u8,
+    /// }
+    /// ```
+    ///
+    /// A field listing `"*"` may be mutated by any function. Fields most functions may mutate can
+    /// instead list the functions that may not with `#[clippy::not_mutatedby(...)]`.
+    /// ```rust
+    /// pub struct MyStruct {
+    ///     #[clippy::not_mutatedby("read_only")]
+    ///     field1: u8,
+    /// }
+    /// ```
+    ///
+    /// A `static mut` item can be restricted the same way, in which case assignments to it are
+    /// checked.
+    /// ```rust
+    /// #[clippy::mutatedby("bump")]
+    /// static mut COUNTER: u32 = 0;
+    ///
+    /// fn bump() {
+    ///     unsafe { COUNTER += 1 };
+    /// }
+    /// ```
+    ///
+    /// ### Configuration
+    /// Setting `fields-mutated-by-whitelist-public-only` limits the check to fields visible outside
+    /// of their module. Setting `fields-mutated-by-whitelist-require-annotation` requires such
+    /// fields to have a `#[clippy::mutatedby(...)]` attribute, turning the check from opt-in to
+    /// opt-out.
+    ///
+    /// A function allowed to mutate a field can hand out a `&mut` to it. Setting
+    /// `fields-mutated-by-whitelist-check-accessors` also checks functions writing through the
+    /// reference returned by such an accessor, e.g. `*value.field_mut() = 1;`.
+    ///
+    /// When a field is mutated by more than `fields-mutated-by-whitelist-blacklist-ratio` methods
+    /// of its type per method that doesn't mutate it, switching to `#[clippy::not_mutatedby(...)]`
+    /// is suggested.
+    ///
+    /// Setting `fields-mutated-by-whitelist-suggest-removal` also suggests removing a disallowed
+    /// assignment that is the only statement of its block.
+    ///
+    /// Setting `fields-mutated-by-whitelist-as-error` emits the lint as an error wherever it would
+    /// otherwise be a warning, e.g. after `#![warn(clippy::fields_mutated_by_whitelist)]`. The
+    /// lint still has to be enabled, and `#[allow]` and `#[expect]` keep silencing it, but a
+    /// `#[warn]` no longer lowers it back to a warning.
+    ///
+    /// Crates using their own tool attribute instead, e.g. `#[my_tool::mutatedby(...)]`, can set
+    /// `fields-mutated-by-whitelist-attribute-name` to its path.
+    ///
+    /// Setting `fields-mutated-by-whitelist-check-transmute` warns about any `transmute` to a
+    /// `&mut` in a function accessing a field it isn't allowed to mutate, in case the reference
+    /// aliases the field.
+    ///
+    /// Setting `fields-mutated-by-whitelist-allow-test-fns` lets `#[test]` functions and functions
+    /// inside `#[cfg(test)]` modules mutate restricted fields without being listed.
+    ///
+    /// Setting the experimental `fields-mutated-by-whitelist-allow-callees` treats the listed
+    /// functions as entry points: the local functions they call directly may mutate the field as
+    /// well, e.g. a `helper` called from an allowed `init`. Functions they call in turn are still
+    /// checked.
+    ///
+    /// Setting `fields-mutated-by-whitelist-group-by-field` reports all disallowed mutations of a
+    /// field in a single warning on its attribute, pointing to each mutation. The lint level is
+    /// then taken from the field, though mutations in functions where the lint is allowed are
+    /// still left out.
+    ///
+    /// Adding the mutating function to the attribute is suggested, unless the attribute would then
+    /// be longer than `fields-mutated-by-whitelist-max-suggestion-length` characters, in which case
+    /// only the function to add is named.
     #[clippy::version = "1.81.0"]
     pub FIELDS_MUTATED_BY_WHITELIST,
     restriction,
-    "ensures that a field is only mutated by functions specified in the #[mutatedby(...)] attribute"
+    "ensures that a field is only mutated by functions specified in the `#[clippy::mutatedby(...)]` attribute"
 }
 
 impl_lint_pass!(FieldsMutatedByWhitelist => [FIELDS_MUTATED_BY_WHITELIST]);
 
+#[expect(clippy::struct_excessive_bools)]
 pub struct FieldsMutatedByWhitelist {
-    pub allowed_functions: FxHashSet<String>,
+    public_only: bool,
+    require_annotation: bool,
+    check_accessors: bool,
+    check_transmute: bool,
+    allow_test_fns: bool,
+    allow_drop: bool,
+    allow_callees: bool,
+    group_by_field: bool,
+    same_module_only: bool,
+    blacklist_ratio: u64,
+    max_suggestion_length: u64,
+    suggest_removal: bool,
+    as_error: bool,
+    attribute_name: &'static str,
+    restrictions: Restrictions,
+    /// The fields each function claims to mutate through `#[clippy::may_mutate]`, along with the
+    /// span of the field name in the attribute. Kept in item order so that unused declarations are
+    /// reported deterministically.
+    declared_mutations: FxIndexMap<LocalDefId, Vec<(LocalDefId, Span)>>,
+    /// The `(function, field)` pairs for which a mutation was seen.
+    observed_mutations: FxHashSet<(LocalDefId, LocalDefId)>,
+    /// The local functions calling each function directly, only collected with `allow_callees`.
+    callers: FxHashMap<LocalDefId, FxHashSet<LocalDefId>>,
+    /// Functions returning a `&mut` along with the restricted fields they mutably borrow.
+    accessors: FxHashMap<LocalDefId, Vec<LocalDefId>>,
+    /// Writes through the result of a call to a local function, e.g. `*value.field_mut() = 1;`,
+    /// as the writing function, the called function and the assignment expression.
+    accessor_writes: Vec<(LocalDefId, LocalDefId, HirId)>,
+    /// The disallowed mutations of each field, as the mutating function, the mutation and the
+    /// accessor it goes through, if any. Only used with `group_by_field`, in which case they are
+    /// reported together in `check_crate_post`.
+    grouped_mutations: FxIndexMap<LocalDefId, Vec<(LocalDefId, HirId, Option<Symbol>)>>,
+}
+
+/// The restrictions collected from the attributes of all items in `check_crate`. Every body has to
+/// be checked against the complete set, so they are frozen before any body is walked, and can only
+/// be added to through methods asserting this.
+#[derive(Default)]
+struct Restrictions {
+    /// The functions allowed to mutate each restricted field, keyed by the field's `DefId`.
+    allowed_functions: FxHashMap<LocalDefId, Entries>,
+    /// The functions a `#[clippy::not_mutatedby]` attribute forbids from mutating each field.
+    denied_functions: FxHashMap<LocalDefId, Entries>,
+    /// The types whose impls may mutate each field, given as `impl = "MyStruct"` entries.
+    allowed_impls: FxHashMap<LocalDefId, Entries>,
+    /// The types whose impls may not mutate each field.
+    denied_impls: FxHashMap<LocalDefId, Entries>,
+    /// The span of the attribute restricting each field, in item order.
+    spans: FxIndexMap<LocalDefId, Span>,
+    /// The restricted fields of each type along with their names, in declaration order.
+    fields_by_type: FxIndexMap<DefId, Vec<(Symbol, LocalDefId)>>,
+    /// The types with a restricted field, computed when the restrictions are frozen. Bodies not
+    /// mentioning any of them or a restricted `static mut` can't mutate a restricted item.
+    tracked_types: FxHashSet<DefId>,
+    frozen: bool,
+}
+
+impl Restrictions {
+    fn is_empty(&self) -> bool {
+        self.allowed_functions.is_empty()
+    }
+
+    fn is_restricted(&self, field: LocalDefId) -> bool {
+        self.allowed_functions.contains_key(&field)
+    }
+
+    fn assert_collecting(&self) {
+        debug_assert!(!self.frozen, "restrictions modified after being frozen");
+    }
+
+    fn freeze(&mut self, tcx: TyCtxt<'_>) {
+        self.tracked_types = self
+            .allowed_functions
+            .keys()
+            .filter(|&&field| tcx.def_kind(field) == DefKind::Field)
+            .map(|field| tcx.parent(field.to_def_id()))
+            .collect();
+        self.frozen = true;
+    }
+
+    /// A cheap check for whether the body may mutate a restricted item, i.e. whether the type of
+    /// any expression or parameter mentions a type with a restricted field, or a restricted
+    /// `static mut` is used. Bodies for which this is false are skipped.
+    fn may_be_mutated_by<'tcx>(&self, cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) -> bool {
+        let mentions_tracked_type = |ty: Ty<'tcx>| {
+            ty.walk().any(|arg| {
+                arg.as_type()
+                    .and_then(Ty::ty_adt_def)
+                    .is_some_and(|adt| self.tracked_types.contains(&adt.did()))
+            })
+        };
+        let typeck = cx.typeck_results();
+        body.params
+            .iter()
+            .any(|param| mentions_tracked_type(typeck.pat_ty(param.pat)))
+            || for_each_expr(cx, body.value, |e| {
+                if mentions_tracked_type(typeck.expr_ty(e))
+                    || mentions_tracked_type(typeck.expr_ty_adjusted(e))
+                    || matches!(
+                        path_res(cx, e),
+                        Res::Def(DefKind::Static { .. }, def_id)
+                            if def_id.as_local().is_some_and(|def_id| self.is_restricted(def_id))
+                    )
+                {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_some()
+    }
+
+    /// Records the attribute restricting the field, keeping the first one.
+    fn restrict(&mut self, field: LocalDefId, attr_span: Span) {
+        self.assert_collecting();
+        self.spans.entry(field).or_insert(attr_span);
+    }
+
+    fn record_field(&mut self, owner: DefId, name: Symbol, field: LocalDefId) {
+        self.assert_collecting();
+        self.fields_by_type.entry(owner).or_default().push((name, field));
+    }
+
+    fn allow(&mut self, field: LocalDefId, names: impl IntoIterator<Item = AttrEntry>, impls: Vec<AttrEntry>) {
+        self.assert_collecting();
+        add_entries(self.allowed_functions.entry(field).or_default(), names);
+        if !impls.is_empty() {
+            add_entries(self.allowed_impls.entry(field).or_default(), impls);
+        }
+    }
+
+    /// Forbids the listed functions from mutating the field. Has to be called after `restrict`.
+    fn deny(&mut self, field: LocalDefId, names: Vec<AttrEntry>, impls: Vec<AttrEntry>) {
+        self.assert_collecting();
+        // Any function not listed may mutate the field, unless it also has a `mutatedby` attribute
+        let attr_span = self.spans[&field];
+        self.allowed_functions
+            .entry(field)
+            .or_insert_with(|| [("*".to_owned(), attr_span)].into_iter().collect());
+        add_entries(self.denied_functions.entry(field).or_default(), names);
+        if !impls.is_empty() {
+            add_entries(self.denied_impls.entry(field).or_default(), impls);
+        }
+    }
 }
 
 impl FieldsMutatedByWhitelist {
-    pub fn new() -> Self {
+    pub fn new(conf: &'static Conf) -> Self {
         Self {
-            allowed_functions: FxHashSet::default(),
+            public_only: conf.fields_mutated_by_whitelist_public_only,
+            require_annotation: conf.fields_mutated_by_whitelist_require_annotation,
+            check_accessors: conf.fields_mutated_by_whitelist_check_accessors,
+            check_transmute: conf.fields_mutated_by_whitelist_check_transmute,
+            allow_test_fns: conf.fields_mutated_by_whitelist_allow_test_fns,
+            allow_drop: conf.fields_mutated_by_whitelist_allow_drop,
+            allow_callees: conf.fields_mutated_by_whitelist_allow_callees,
+            group_by_field: conf.fields_mutated_by_whitelist_group_by_field,
+            same_module_only: conf.fields_mutated_by_whitelist_same_module_only,
+            blacklist_ratio: conf.fields_mutated_by_whitelist_blacklist_ratio,
+            max_suggestion_length: conf.fields_mutated_by_whitelist_max_suggestion_length,
+            suggest_removal: conf.fields_mutated_by_whitelist_suggest_removal,
+            as_error: conf.fields_mutated_by_whitelist_as_error,
+            attribute_name: &conf.fields_mutated_by_whitelist_attribute_name,
+            restrictions: Restrictions::default(),
+            declared_mutations: FxIndexMap::default(),
+            observed_mutations: FxHashSet::default(),
+            callers: FxHashMap::default(),
+            accessors: FxHashMap::default(),
+            accessor_writes: Vec::new(),
+            grouped_mutations: FxIndexMap::default(),
         }
     }
 
-    pub fn add_function(&mut self, function_name: &str) {
-        self.allowed_functions.insert(function_name.to_string());
+    /// The restricted fields of the type, along with the functions allowed to mutate each of them,
+    /// for drivers reporting on the restrictions of a crate. The restrictions are collected in
+    /// `check_crate`, and kept until the next crate is checked.
+    pub fn protected_fields(&self, def_id: DefId) -> impl Iterator<Item = (Symbol, &Entries)> {
+        self.restrictions
+            .fields_by_type
+            .get(&def_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|(name, field)| Some((*name, self.restrictions.allowed_functions.get(field)?)))
+    }
+
+    /// Matches the entries against the path of the function's definition, so naming it through an
+    /// alias at the call site neither grants nor removes the permission. Methods also match as
+    /// `Type::method`, which the definition path of a trait impl method, `<Type as Trait>::method`,
+    /// doesn't. `impl = ".."` entries are matched against the path of the `Self` type of the impl
+    /// containing the function. Test functions are allowed if `allow_test_fns` is set, and the
+    /// `drop` method of the `Drop` impl of the field's type if `allow_drop` is set, unless a denial
+    /// names them explicitly. With `same_module_only`, functions outside of the module
+    /// defining the field are always allowed. With `allow_callees`, so are the functions called
+    /// directly by an allowed function, unless they are denied themselves.
+    fn is_allowed(&self, cx: &LateContext<'_>, field: LocalDefId, fn_def_id: LocalDefId) -> bool {
+        match self.is_allowed_directly(cx, field, fn_def_id) {
+            Some(allowed) => {
+                allowed
+                    || (self.allow_callees
+                        && self.callers.get(&fn_def_id).is_some_and(|callers| {
+                            callers
+                                .iter()
+                                .any(|&caller| self.is_allowed_directly(cx, field, caller) == Some(true))
+                        }))
+            },
+            None => false,
+        }
+    }
+
+    /// Checks whether the function is allowed to mutate the field by itself, returning `None` if
+    /// it's explicitly denied.
+    fn is_allowed_directly(&self, cx: &LateContext<'_>, field: LocalDefId, fn_def_id: LocalDefId) -> Option<bool> {
+        if self.same_module_only
+            && cx.tcx.parent_module_from_def_id(fn_def_id) != cx.tcx.parent_module_from_def_id(field)
+        {
+            return Some(true);
+        }
+        let (paths, self_ty_path) = fn_paths(cx, fn_def_id);
+        let denied = self.denying_entry(cx, field, fn_def_id).is_some();
+        let allowed = matching_entry(self.restrictions.allowed_functions.get(&field), &paths).is_some()
+            || matching_entry(self.restrictions.allowed_impls.get(&field), &self_ty_path).is_some();
+        (!denied).then(|| {
+            allowed
+                || (self.allow_test_fns && is_in_test(cx.tcx, cx.tcx.local_def_id_to_hir_id(fn_def_id)))
+                || (self.allow_drop && is_drop_of_owner(cx, field, fn_def_id))
+        })
+    }
+
+    /// Finds the `#[clippy::not_mutatedby]` entry forbidding the function from mutating the field,
+    /// as it is written in the attribute along with its span.
+    fn denying_entry(&self, cx: &LateContext<'_>, field: LocalDefId, fn_def_id: LocalDefId) -> Option<AttrEntry> {
+        let (paths, self_ty_path) = fn_paths(cx, fn_def_id);
+        matching_entry(self.restrictions.denied_impls.get(&field), &self_ty_path)
+            .map(|(pattern, span)| (format!("impl = \"{pattern}\""), span))
+            .or_else(|| matching_entry(self.restrictions.denied_functions.get(&field), &paths))
+    }
+
+    /// Reports a disallowed mutation, or records it to be reported along with the other mutations
+    /// of the field with `group_by_field`.
+    fn report_mutation(
+        &mut self,
+        cx: &LateContext<'_>,
+        hir_id: HirId,
+        field: LocalDefId,
+        fn_def_id: LocalDefId,
+        accessor: Option<Symbol>,
+    ) {
+        if self.group_by_field {
+            self.grouped_mutations
+                .entry(field)
+                .or_default()
+                .push((fn_def_id, hir_id, accessor));
+        } else {
+            self.lint_mutation(cx, hir_id, field, fn_def_id, accessor);
+        }
+    }
+
+    fn lint_mutation(
+        &self,
+        cx: &LateContext<'_>,
+        hir_id: HirId,
+        field: LocalDefId,
+        fn_def_id: LocalDefId,
+        accessor: Option<Symbol>,
+    ) {
+        let fn_name = cx.tcx.item_name(fn_def_id.to_def_id());
+        let field_name = cx.tcx.item_name(field.to_def_id());
+        let descr = restricted_descr(cx, field);
+        let msg = if let Some(accessor) = accessor {
+            format!(
+                "{descr} `{field_name}` is mutated by `{fn_name}` through `{accessor}`, but `{fn_name}` is not allowed to mutate it"
+            )
+        } else {
+            format!("{descr} `{field_name}` is mutated by `{fn_name}`, which is not allowed to mutate it")
+        };
+        let span = cx.tcx.hir().span(hir_id);
+        emit(cx, self.as_error, hir_id, span, msg, |diag| {
+            diag.span_label(span, "disallowed mutation here");
+            diag.span_note(self.declaration_span(cx, field), declaration_note(cx, field));
+            if let Some((entry, entry_span)) = self.denying_entry(cx, field, fn_def_id) {
+                diag.span_help(
+                    entry_span,
+                    format!(
+                        "if this mutation is intended, remove `{entry}` from the `#[clippy::not_mutatedby]` attribute of `{field_name}`"
+                    ),
+                );
+            } else {
+                let help = format!(
+                    "if this mutation is intended, add `{fn_name}` to the `#[{}]` attribute of `{field_name}`",
+                    attribute_path(self.attribute_name),
+                );
+                if let Some((sugg_span, sugg)) = self.whitelist_sugg(cx, field, fn_name) {
+                    diag.span_suggestion_verbose(sugg_span, help, sugg, Applicability::MaybeIncorrect);
+                } else {
+                    diag.help(help);
+                }
+            }
+            if let Some(setter) = self.find_setter(cx, field) {
+                diag.help(format!("consider calling `{setter}(...)` instead"));
+            }
+            if self.suggest_removal
+                && accessor.is_none()
+                && let Some(stmt) = standalone_assignment(cx, hir_id)
+            {
+                diag.span_suggestion_verbose(
+                    stmt.span,
+                    "otherwise, remove the assignment",
+                    "",
+                    Applicability::MaybeIncorrect,
+                );
+            }
+            // Kept in a fixed format so that tooling consuming the JSON output can rely on it.
+            diag.note(format!("field={field_name} violated_by={fn_name}"));
+        });
+    }
+
+    /// Suggests adding the function to the restriction attribute, by inserting it before the
+    /// closing parenthesis. Nothing is suggested if the attribute would get longer than
+    /// `max_suggestion_length`, as the suggestion would then be hard to read.
+    fn whitelist_sugg(&self, cx: &LateContext<'_>, field: LocalDefId, fn_name: Symbol) -> Option<(Span, String)> {
+        let &attr_span = self.restrictions.spans.get(&field)?;
+        let attr = snippet_opt(cx, attr_span)?;
+        let args = attr.strip_suffix(")]")?;
+        let sugg = if args.trim_end().ends_with(['(', ',']) {
+            format!("\"{fn_name}\"")
+        } else {
+            format!(", \"{fn_name}\"")
+        };
+        if (attr.len() + sugg.len()) as u64 > self.max_suggestion_length {
+            return None;
+        }
+        let pos = attr_span.lo() + BytePos(u32::try_from(args.len()).ok()?);
+        Some((attr_span.with_lo(pos).with_hi(pos), sugg))
+    }
+
+    /// The span of the declaration of a restricted item, along with its restriction attribute.
+    fn declaration_span(&self, cx: &LateContext<'_>, def_id: LocalDefId) -> MultiSpan {
+        let mut span = MultiSpan::from_span(cx.tcx.def_span(def_id));
+        // Items only restricted through `#[clippy::may_mutate]` have no attribute to point to
+        if let Some(&restriction_span) = self.restrictions.spans.get(&def_id) {
+            span.push_span_label(restriction_span, "restricted here");
+        }
+        span
+    }
+
+    /// Reports the mutations recorded with `group_by_field`, with a single diagnostic per field
+    /// pointing to each of them. Mutations in functions where the lint is allowed are left out.
+    fn lint_grouped_mutations(&mut self, cx: &LateContext<'_>) {
+        for (field, mutations) in std::mem::take(&mut self.grouped_mutations) {
+            let mutations = mutations
+                .into_iter()
+                .filter(|&(_, hir_id, _)| !is_lint_allowed(cx, FIELDS_MUTATED_BY_WHITELIST, hir_id))
+                .collect::<Vec<_>>();
+            if mutations.is_empty() {
+                continue;
+            }
+            let field_name = cx.tcx.item_name(field.to_def_id());
+            let descr = restricted_descr(cx, field);
+            let fn_names = mutations
+                .iter()
+                .map(|&(fn_def_id, ..)| cx.tcx.item_name(fn_def_id.to_def_id()))
+                .unique()
+                .collect::<Vec<_>>();
+            let msg = if let [fn_name] = fn_names[..] {
+                format!("{descr} `{field_name}` is mutated by `{fn_name}`, which is not allowed to mutate it")
+            } else {
+                format!(
+                    "{descr} `{field_name}` is mutated by {} functions which are not allowed to mutate it",
+                    fn_names.len()
+                )
+            };
+            // Fields only restricted through `#[clippy::may_mutate]` have no attribute to point to
+            let restriction_span = self
+                .restrictions
+                .spans
+                .get(&field)
+                .copied()
+                .unwrap_or_else(|| cx.tcx.def_span(field));
+            let mut span = MultiSpan::from_span(restriction_span);
+            for &(fn_def_id, hir_id, accessor) in &mutations {
+                let fn_name = cx.tcx.item_name(fn_def_id.to_def_id());
+                let label = if let Some(accessor) = accessor {
+                    format!("mutated by `{fn_name}` through `{accessor}`")
+                } else {
+                    format!("mutated by `{fn_name}`")
+                };
+                span.push_span_label(cx.tcx.hir().span(hir_id), label);
+            }
+            let names = fn_names.iter().map(|name| format!("`{name}`")).join(", ");
+            let denying_entries = mutations
+                .iter()
+                .filter_map(|&(fn_def_id, ..)| self.denying_entry(cx, field, fn_def_id))
+                .unique()
+                .collect::<Vec<_>>();
+            emit(
+                cx,
+                self.as_error,
+                cx.tcx.local_def_id_to_hir_id(field),
+                span,
+                msg,
+                |diag| {
+                    if denying_entries.is_empty() {
+                        diag.help(format!(
+                            "if these mutations are intended, add {names} to the `#[{}]` attribute of `{field_name}`",
+                            attribute_path(self.attribute_name),
+                        ));
+                    } else {
+                        let entries = denying_entries.iter().map(|(entry, _)| format!("`{entry}`")).join(", ");
+                        let spans = denying_entries.iter().map(|&(_, span)| span).collect::<Vec<_>>();
+                        diag.span_help(
+                            spans,
+                            format!(
+                                "if these mutations are intended, remove {entries} from the `#[clippy::not_mutatedby]` attribute of `{field_name}`"
+                            ),
+                        );
+                    }
+                    diag.span_note(cx.tcx.def_span(field), declaration_note(cx, field));
+                    // Kept in the ungrouped format for tooling consuming the JSON output
+                    for fn_name in &fn_names {
+                        diag.note(format!("field={field_name} violated_by={fn_name}"));
+                    }
+                },
+            );
+        }
+    }
+
+    /// Warns about `transmute` calls producing a `&mut` in a function accessing a restricted field
+    /// it isn't allowed to mutate. Whether the reference aliases the field is unknown, so this only
+    /// runs with `fields-mutated-by-whitelist-check-transmute`.
+    fn check_transmutes<'tcx>(&self, cx: &LateContext<'tcx>, def_id: LocalDefId, body: &'tcx Body<'tcx>) {
+        let fn_name = cx.tcx.item_name(def_id.to_def_id());
+        let mut transmutes = Vec::new();
+        let mut fields = FxIndexSet::default();
+        for_each_expr(cx, body.value, |e| {
+            if let ExprKind::Call(func, [_]) = e.kind
+                && let Some(func_def_id) = path_def_id(cx, func)
+                && cx.tcx.is_diagnostic_item(sym::transmute, func_def_id)
+                && let ty::Ref(_, _, Mutability::Mut) = cx.typeck_results().expr_ty(e).kind()
+            {
+                transmutes.push(e);
+            } else if let Some(field) = accessed_field(cx, e)
+                && self.restrictions.is_restricted(field)
+                && !self.is_allowed(cx, field, def_id)
+            {
+                fields.insert(field);
+            }
+            ControlFlow::<!, _>::Continue(())
+        });
+
+        for transmute in transmutes {
+            for &field in &fields {
+                let field_name = cx.tcx.item_name(field.to_def_id());
+                emit(
+                    cx,
+                    self.as_error,
+                    transmute.hir_id,
+                    transmute.span,
+                    format!(
+                        "`{fn_name}` transmutes to a `&mut` which may alias field `{field_name}`, but is not allowed to mutate it"
+                    ),
+                    |diag| {
+                        if let Some(&restriction_span) = self.restrictions.spans.get(&field) {
+                            diag.span_note(restriction_span, "field restricted here");
+                        }
+                        diag.note("the reference is not known to point to the field, this warning is conservative");
+                    },
+                );
+            }
+        }
+    }
+
+    /// Finds the method of the field's type that looks like a setter for it, i.e. is allowed to
+    /// mutate it, is named `set_*` and takes a single value of the field's type. Nothing is
+    /// returned if there are several such methods.
+    fn find_setter(&self, cx: &LateContext<'_>, field: LocalDefId) -> Option<Symbol> {
+        if cx.tcx.def_kind(field) != DefKind::Field {
+            return None;
+        }
+        let adt = cx
+            .tcx
+            .type_of(cx.tcx.parent(field.to_def_id()))
+            .instantiate_identity()
+            .ty_adt_def()?;
+        let field_ty = cx.tcx.type_of(field).instantiate_identity();
+        let mut setters = cx
+            .tcx
+            .inherent_impls(adt.did())
+            .into_iter()
+            .flatten()
+            .flat_map(|&impl_id| cx.tcx.associated_items(impl_id).in_definition_order())
+            .filter(|item| {
+                item.kind == AssocKind::Fn && item.fn_has_self_parameter && item.name.as_str().starts_with("set_")
+            })
+            .filter_map(|item| item.def_id.as_local())
+            .filter(|&method| {
+                matches!(
+                    cx.tcx.fn_sig(method).instantiate_identity().skip_binder().inputs(),
+                    [_, arg_ty] if *arg_ty == field_ty
+                ) && self.is_allowed(cx, field, method)
+            });
+        let setter = setters.next()?;
+        setters.next().is_none().then(|| cx.tcx.item_name(setter.to_def_id()))
+    }
+
+    /// Suggests `#[clippy::not_mutatedby]` for fields mutated by nearly all methods of their type,
+    /// for which listing the few methods that don't mutate them is clearer.
+    fn suggest_blacklists(&self, cx: &LateContext<'_>) {
+        for (&field, &attr_span) in &self.restrictions.spans {
+            if cx.tcx.def_kind(field) != DefKind::Field
+                || self.restrictions.denied_functions.contains_key(&field)
+                || self.restrictions.allowed_functions[&field].contains_key("*")
+            {
+                continue;
+            }
+            let Some(adt) = cx
+                .tcx
+                .type_of(cx.tcx.parent(field.to_def_id()))
+                .instantiate_identity()
+                .ty_adt_def()
+            else {
+                continue;
+            };
+            let (mutating, non_mutating): (Vec<_>, Vec<_>) = cx
+                .tcx
+                .inherent_impls(adt.did())
+                .into_iter()
+                .flatten()
+                .flat_map(|&impl_id| cx.tcx.associated_items(impl_id).in_definition_order())
+                .filter(|item| item.kind == AssocKind::Fn)
+                .filter_map(|item| item.def_id.as_local())
+                // Disallowed mutations are already reported on their own
+                .filter(|&method| {
+                    !self.observed_mutations.contains(&(method, field)) || self.is_allowed(cx, field, method)
+                })
+                .partition(|&method| self.observed_mutations.contains(&(method, field)));
+
+            if !non_mutating.is_empty() && mutating.len() as u64 > self.blacklist_ratio * non_mutating.len() as u64 {
+                let field_name = cx.tcx.item_name(field.to_def_id());
+                let names = non_mutating
+                    .iter()
+                    .map(|&method| format!("\"{}\"", cx.tcx.item_name(method.to_def_id())))
+                    .join(", ");
+                emit(
+                    cx,
+                    self.as_error,
+                    cx.tcx.local_def_id_to_hir_id(field),
+                    attr_span,
+                    format!(
+                        "field `{field_name}` is mutated by {} of the {} methods of `{}`",
+                        mutating.len(),
+                        mutating.len() + non_mutating.len(),
+                        cx.tcx.item_name(adt.did()),
+                    ),
+                    |diag| {
+                        diag.help(format!(
+                            "consider listing the methods that don't mutate it instead: `#[clippy::not_mutatedby({names})]`"
+                        ));
+                    },
+                );
+            }
+        }
     }
 }
 
-impl LateLintPass<'_> for FieldsMutatedByWhitelist {
-    fn check_crate(&mut self, cx: &LateContext<'_>) {
+/// The restrictions are collected from all items in `check_crate`, mutations are checked body by
+/// body in `check_fn`, and anything depending on other bodies, i.e. unused declarations and writes
+/// through accessors, is reported in `check_crate_post`, which also resets the state. The
+/// restrictions are only reset by the next `check_crate`, so drivers can still query them.
+impl<'tcx> LateLintPass<'tcx> for FieldsMutatedByWhitelist {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        self.restrictions = Restrictions::default();
         let mut visitor = FieldVisitor {
             cx,
-            allowed_functions: &mut self.allowed_functions,
+            public_only: self.public_only,
+            require_annotation: self.require_annotation,
+            as_error: self.as_error,
+            attribute_name: self.attribute_name,
+            restrictions: &mut self.restrictions,
+            declared_mutations: &mut self.declared_mutations,
         };
         cx.tcx.hir().visit_all_item_likes_in_crate(&mut visitor);
+        self.restrictions.freeze(cx.tcx);
+        if self.allow_callees && !self.restrictions.is_empty() {
+            self.callers = collect_callers(cx.tcx);
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        def_id: LocalDefId,
+    ) {
+        // Closures are walked as part of their enclosing function, which is the one the mutation is
+        // attributed to.
+        if self.restrictions.is_empty() || matches!(kind, FnKind::Closure) {
+            return;
+        }
+        // Writes through an accessor don't need to mention the accessor's type, e.g. when it
+        // returns a field of a `static`, so they are collected before skipping the body.
+        if self.check_accessors {
+            collect_accessor_writes(cx, def_id, body, &mut self.accessor_writes);
+        }
+        // Most bodies don't touch any restricted item, so the full analysis is skipped for them.
+        let dump = has_dump_attr(cx, def_id);
+        if !self.restrictions.may_be_mutated_by(cx, body) {
+            if dump {
+                println!("{}: skipped", cx.tcx.def_path_str(def_id));
+            }
+            return;
+        }
+
+        let mut delegate = MutationDelegate {
+            cx,
+            restrictions: &self.restrictions,
+            mutations: Vec::new(),
+        };
+        ExprUseVisitor::for_clippy(cx, def_id, &mut delegate)
+            .consume_body(body)
+            .into_ok();
+        collect_static_writes(cx, body, &self.restrictions, &mut delegate.mutations);
+        collect_interior_mutations(cx, body, &self.restrictions, &mut delegate.mutations);
+        if dump {
+            let mutated = delegate
+                .mutations
+                .iter()
+                .map(|&(item, _)| cx.tcx.item_name(item.to_def_id()))
+                .unique()
+                .join(", ");
+            println!("{}: checked, mutates [{mutated}]", cx.tcx.def_path_str(def_id));
+        }
+
+        if self.check_accessors {
+            if let ty::Ref(_, _, Mutability::Mut) = cx
+                .tcx
+                .fn_sig(def_id)
+                .instantiate_identity()
+                .skip_binder()
+                .output()
+                .kind()
+            {
+                let fields = delegate.mutations.iter().map(|&(field, _)| field).collect();
+                self.accessors.insert(def_id, fields);
+            }
+        }
+
+        for (field, hir_id) in delegate.mutations {
+            self.observed_mutations.insert((def_id, field));
+            if !self.is_allowed(cx, field, def_id) {
+                self.report_mutation(cx, hir_id, field, def_id, None);
+            }
+        }
+
+        if self.check_transmute {
+            self.check_transmutes(cx, def_id, body);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (fn_def_id, declared) in std::mem::take(&mut self.declared_mutations) {
+            let fn_name = cx.tcx.item_name(fn_def_id.to_def_id());
+            for (field, name_span) in declared {
+                if self.observed_mutations.contains(&(fn_def_id, field)) {
+                    continue;
+                }
+                let field_name = cx.tcx.item_name(field.to_def_id());
+                emit(
+                    cx,
+                    self.as_error,
+                    cx.tcx.local_def_id_to_hir_id(fn_def_id),
+                    name_span,
+                    format!("`{fn_name}` is declared to mutate `{field_name}`, but never does"),
+                    |diag| {
+                        diag.help(format!(
+                            "remove `{field_name}` from the `#[clippy::may_mutate]` attribute"
+                        ));
+                    },
+                );
+            }
+        }
+
+        for (fn_def_id, accessor, hir_id) in std::mem::take(&mut self.accessor_writes) {
+            let accessor_name = cx.tcx.item_name(accessor.to_def_id());
+            let fields = self.accessors.get(&accessor).cloned().unwrap_or_default();
+            for field in fields {
+                // Accessors that aren't allowed to mutate the field are reported on their own
+                if self.is_allowed(cx, field, accessor) && !self.is_allowed(cx, field, fn_def_id) {
+                    self.report_mutation(cx, hir_id, field, fn_def_id, Some(accessor_name));
+                }
+            }
+        }
+
+        self.lint_grouped_mutations(cx);
+        self.suggest_blacklists(cx);
+
+        self.observed_mutations.clear();
+        self.callers.clear();
+        self.accessors.clear();
+    }
+}
+
+/// Maps each local function to the local functions calling it directly. Calls from closures are
+/// attributed to the enclosing function, as mutations are.
+fn collect_callers(tcx: TyCtxt<'_>) -> FxHashMap<LocalDefId, FxHashSet<LocalDefId>> {
+    let mut callers: FxHashMap<LocalDefId, FxHashSet<LocalDefId>> = FxHashMap::default();
+    for owner in tcx.hir().body_owners() {
+        let calling_fn = tcx.typeck_root_def_id(owner.to_def_id()).expect_local();
+        if !matches!(tcx.def_kind(calling_fn), DefKind::Fn | DefKind::AssocFn) {
+            continue;
+        }
+        let typeck_results = tcx.typeck(owner);
+        for_each_expr_without_closures(tcx.hir().body_owned_by(owner).value, |e| {
+            let callee = match e.kind {
+                ExprKind::Call(func, _) if let ExprKind::Path(ref qpath) = func.kind => {
+                    typeck_results.qpath_res(qpath, func.hir_id).opt_def_id()
+                },
+                ExprKind::MethodCall(..) => typeck_results.type_dependent_def_id(e.hir_id),
+                _ => None,
+            };
+            if let Some(callee) = callee.and_then(DefId::as_local)
+                && matches!(tcx.def_kind(callee), DefKind::Fn | DefKind::AssocFn)
+            {
+                callers.entry(callee).or_default().insert(calling_fn);
+            }
+            ControlFlow::<!, _>::Continue(())
+        });
+    }
+    callers
+}
+
+/// Collects the assignments through the `&mut` returned by a call to a local function, e.g.
+/// `*value.field_mut() = 1;` or `*field_mut(&mut value) += 1;`.
+fn collect_accessor_writes<'tcx>(
+    cx: &LateContext<'tcx>,
+    caller: LocalDefId,
+    body: &'tcx Body<'tcx>,
+    writes: &mut Vec<(LocalDefId, LocalDefId, HirId)>,
+) {
+    for_each_expr(cx, body.value, |e| {
+        if let ExprKind::Assign(lhs, _, _) | ExprKind::AssignOp(_, lhs, _) = e.kind
+            && let ExprKind::Unary(UnOp::Deref, call) = lhs.kind
+            && let ExprKind::Call(..) | ExprKind::MethodCall(..) = call.kind
+            && let Some(accessor) = fn_def_id(cx, call).and_then(DefId::as_local)
+        {
+            writes.push((caller, accessor, e.hir_id));
+        }
+        ControlFlow::<!, _>::Continue(())
+    });
+}
+
+/// Collects the assignments to restricted `static mut` items, e.g. `COUNTER += 1;` or
+/// `STATE.value = 1;`. Places based on a static don't record which static it is, so these aren't
+/// seen by the `MutationDelegate`.
+fn collect_static_writes<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx Body<'tcx>,
+    restrictions: &Restrictions,
+    mutations: &mut Vec<(LocalDefId, HirId)>,
+) {
+    for_each_expr(cx, body.value, |e| {
+        if let ExprKind::Assign(mut lhs, _, _) | ExprKind::AssignOp(_, mut lhs, _) = e.kind {
+            while let ExprKind::Field(base, _) | ExprKind::Index(base, _, _) = lhs.kind {
+                lhs = base;
+            }
+            if let Res::Def(DefKind::Static { .. }, def_id) = path_res(cx, lhs)
+                && let Some(def_id) = def_id.as_local()
+                && restrictions.is_restricted(def_id)
+            {
+                mutations.push((def_id, e.hir_id));
+            }
+        }
+        ControlFlow::<!, _>::Continue(())
+    });
+}
+
+/// Collects the calls mutating a restricted field through interior mutability, i.e.
+/// `RefCell::borrow_mut`, `Cell::set` and `Cell::replace`, e.g. `self.field.set(1);`. These only
+/// take `&self`, so the `MutationDelegate` sees them as immutable borrows.
+fn collect_interior_mutations<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx Body<'tcx>,
+    restrictions: &Restrictions,
+    mutations: &mut Vec<(LocalDefId, HirId)>,
+) {
+    for_each_expr(cx, body.value, |e| {
+        if let ExprKind::MethodCall(_, receiver, _, _) = e.kind
+            && let Some(method) = cx.typeck_results().type_dependent_def_id(e.hir_id)
+            && [&paths::REFCELL_BORROW_MUT, &paths::CELL_SET, &paths::CELL_REPLACE]
+                .into_iter()
+                .any(|path| match_def_path(cx, method, path))
+        {
+            // Any restricted field along the way is mutated, e.g. both `outer` and `inner` in
+            // `self.outer.inner.set(1)`
+            let mut place = receiver.peel_borrows();
+            while let ExprKind::Field(base, _) = place.kind {
+                if let Some(field) = accessed_field(cx, place)
+                    && restrictions.is_restricted(field)
+                    && !mutations.contains(&(field, receiver.hir_id))
+                {
+                    mutations.push((field, receiver.hir_id));
+                }
+                place = base;
+            }
+        }
+        ControlFlow::<!, _>::Continue(())
+    });
+}
+
+/// Returns the local field accessed by a field expression, e.g. `field` in `self.field`.
+fn accessed_field(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<LocalDefId> {
+    if let ExprKind::Field(base, _) = expr.kind
+        && let Some(field_idx) = cx.typeck_results().opt_field_index(expr.hir_id)
+        && let ty::Adt(adt, _) = cx.typeck_results().expr_ty_adjusted(base).peel_refs().kind()
+        && adt.is_struct()
+    {
+        adt.non_enum_variant().fields[field_idx].did.as_local()
+    } else {
+        None
+    }
+}
+
+/// Finds the statement consisting of the assignment to `lhs_id`, e.g. `self.field = 1;`, if it is
+/// the only statement of its block. Removing it then leaves nothing behind depending on it.
+fn standalone_assignment<'tcx>(cx: &LateContext<'tcx>, lhs_id: HirId) -> Option<&'tcx Stmt<'tcx>> {
+    if let Node::Expr(expr) = cx.tcx.parent_hir_node(lhs_id)
+        && let ExprKind::Assign(lhs, _, _) = expr.kind
+        && lhs.hir_id == lhs_id
+        && !expr.span.from_expansion()
+        && let Node::Stmt(stmt) = cx.tcx.parent_hir_node(expr.hir_id)
+        && let StmtKind::Semi(_) = stmt.kind
+        && let Node::Block(block) = cx.tcx.parent_hir_node(stmt.hir_id)
+        && let [_] = block.stmts
+        && block.expr.is_none()
+    {
+        Some(stmt)
+    } else {
+        None
+    }
+}
+
+/// Emits the lint at the given node, as an error instead of a warning when
+/// `fields-mutated-by-whitelist-as-error` is set.
+fn emit(
+    cx: &LateContext<'_>,
+    as_error: bool,
+    hir_id: HirId,
+    span: impl Into<MultiSpan>,
+    msg: String,
+    f: impl FnOnce(&mut Diag<'_, ()>),
+) {
+    if as_error {
+        span_lint_hir_and_then_as_error(cx, FIELDS_MUTATED_BY_WHITELIST, hir_id, span, msg, f);
+    } else {
+        span_lint_hir_and_then(cx, FIELDS_MUTATED_BY_WHITELIST, hir_id, span, msg, f);
+    }
+}
+
+/// The path of the `Self` type of the impl containing the function, if any.
+fn self_ty_path(cx: &LateContext<'_>, fn_def_id: LocalDefId) -> Option<String> {
+    cx.tcx
+        .impl_of_method(fn_def_id.to_def_id())
+        .and_then(|impl_id| cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def())
+        .map(|adt| cx.tcx.def_path_str(adt.did()))
+}
+
+/// Whether the function is `Drop::drop` of the type declaring the field.
+fn is_drop_of_owner(cx: &LateContext<'_>, field: LocalDefId, fn_def_id: LocalDefId) -> bool {
+    if cx.tcx.def_kind(field) == DefKind::Field
+        && let Some(impl_id) = cx.tcx.impl_of_method(fn_def_id.to_def_id())
+        && let Some(trait_id) = cx.tcx.trait_id_of_impl(impl_id)
+        && cx.tcx.lang_items().drop_trait() == Some(trait_id)
+        && let Some(adt) = cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def()
+    {
+        adt.did() == cx.tcx.parent(field.to_def_id())
+    } else {
+        false
+    }
+}
+
+/// Describes the declaration of a restricted item, e.g. "field `count` of type `u32` is declared
+/// here".
+fn declaration_note(cx: &LateContext<'_>, def_id: LocalDefId) -> String {
+    format!(
+        "{} `{}` of type `{}` is declared here",
+        restricted_descr(cx, def_id),
+        cx.tcx.item_name(def_id.to_def_id()),
+        with_forced_trimmed_paths!(cx.tcx.type_of(def_id).instantiate_identity().to_string()),
+    )
+}
+
+/// Whether the restricted item is a field or a `static mut`, as used in the messages.
+fn restricted_descr(cx: &LateContext<'_>, def_id: LocalDefId) -> &'static str {
+    if matches!(cx.tcx.def_kind(def_id), DefKind::Static { .. }) {
+        "static"
+    } else {
+        "field"
     }
 }
 
 struct FieldVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
-    allowed_functions: &'a mut FxHashSet<String>,
+    public_only: bool,
+    require_annotation: bool,
+    as_error: bool,
+    attribute_name: &'static str,
+    restrictions: &'a mut Restrictions,
+    declared_mutations: &'a mut FxIndexMap<LocalDefId, Vec<(LocalDefId, Span)>>,
 }
 
 impl<'a, 'tcx> Visitor<'tcx> for FieldVisitor<'a, 'tcx> {
-    fn visit_item(&mut self, item: &'tcx rustc_hir::Item<'tcx>) {
-    if let ItemKind::Struct(ref _struct, _) = item.kind {
-        self.check_struct_fields(item.hir_id());
+    fn visit_item(&mut self, item: &'tcx Item<'tcx>) {
+        match item.kind {
+            ItemKind::Struct(ref variant_data, _) => {
+                for field in variant_data.fields() {
+                    self.check_struct_fields(field);
+                }
+            },
+            ItemKind::Static(_, Mutability::Mut, _) if self.is_checked(item.owner_id.def_id) => {
+                self.collect_restrictions(item.owner_id.def_id, self.cx.tcx.hir().attrs(item.hir_id()));
+            },
+            _ => {},
+        }
+        intravisit::walk_item(self, item);
+    }
+
+    fn visit_impl_item(&mut self, impl_item: &'tcx ImplItem<'tcx>) {
+        if let ImplItemKind::Fn(..) = impl_item.kind {
+            self.check_fn_attrs(impl_item.owner_id.def_id);
+        }
+        intravisit::walk_impl_item(self, impl_item);
     }
-    intravisit::walk_item(self, item);
-}
 }
 
 impl<'a, 'tcx> FieldVisitor<'a, 'tcx> {
-    fn check_struct_fields(&mut self, struct_hir_id: HirId) {
-        let attrs = self.cx.tcx.hir().attrs(struct_hir_id);
-
-        for attr in attrs {
-            if let AttrKind::Normal(normal_attr) = &attr.kind {
-                // Correct pattern matching for `AttrArgs::Delimited`
-                if let AttrArgs::Delimited(delimited) = &normal_attr.item.args {
-                    let token_trees = delimited.tokens.trees();
-
-                    // Collect function names from tokens
-                    let function_names: Vec<String> = token_trees
-                        .filter_map(|tt| match tt {
-                            TokenTree::Token(
-                                Token {
-                                    kind: TokenKind::Ident(ident, _),
-                                    ..
-                                },
-                                _,
-                            ) => Some(ident.to_string()),
-                            _ => None,
-                        })
-                        .collect();
-
-                    // Add each function name to the allowed functions
-                    for function_name in function_names.clone() {
-                        self.allowed_functions.insert(function_name);
+    fn is_private(&self, field_def_id: LocalDefId) -> bool {
+        let tcx = self.cx.tcx;
+        tcx.visibility(field_def_id)
+            == ty::Visibility::Restricted(tcx.parent_module_from_def_id(field_def_id).to_def_id())
+    }
+
+    /// Whether restrictions on the field are enforced. With `public_only`, fields that are private
+    /// to their module are skipped.
+    fn is_checked(&self, field_def_id: LocalDefId) -> bool {
+        !self.public_only || !self.is_private(field_def_id)
+    }
+
+    fn check_struct_fields(&mut self, field: &FieldDef<'_>) {
+        let field_def_id = field.def_id;
+        if !self.is_checked(field_def_id) {
+            return;
+        }
+        let attrs = self.cx.tcx.hir().attrs(field.hir_id);
+
+        if self.require_annotation
+            && restriction_attrs(self.cx.sess(), attrs, self.attribute_name).is_empty()
+            && get_attr(self.cx.sess(), attrs, "not_mutatedby").next().is_none()
+            && !self.is_private(field_def_id)
+        {
+            emit(
+                self.cx,
+                self.as_error,
+                field.hir_id,
+                field.span,
+                format!(
+                    "field `{}` is visible outside of its module, but has no `#[{}]` attribute",
+                    field.ident,
+                    attribute_path(self.attribute_name),
+                ),
+                |diag| {
+                    diag.help(format!(
+                        "list the functions allowed to mutate it, or use `#[{}(\"*\")]` to allow any function",
+                        attribute_path(self.attribute_name),
+                    ));
+                },
+            );
+        }
+
+        self.collect_restrictions(field_def_id, attrs);
+        if self.restrictions.is_restricted(field_def_id) {
+            let owner = self.cx.tcx.parent(field_def_id.to_def_id());
+            self.restrictions.record_field(owner, field.ident.name, field_def_id);
+        }
+    }
+
+    /// Collects the functions the attributes of a field or a `static mut` allow or forbid to mutate
+    /// it.
+    fn collect_restrictions(&mut self, field_def_id: LocalDefId, attrs: &[Attribute]) {
+        let denied = get_attr(self.cx.sess(), attrs, "not_mutatedby");
+        for attr in restriction_attrs(self.cx.sess(), attrs, self.attribute_name) {
+            self.restrictions.restrict(field_def_id, attr.span);
+            let (names, impls) = attr_entries(attr);
+            self.restrictions.allow(field_def_id, names, impls);
+        }
+        for attr in denied {
+            self.restrictions.restrict(field_def_id, attr.span);
+            let (names, impls) = attr_entries(attr);
+            self.restrictions.deny(field_def_id, names, impls);
+        }
+    }
+
+    /// Collects the fields a method grants itself through `#[clippy::may_mutate(...)]`. The
+    /// listed names refer to fields of the `Self` type of the surrounding impl.
+    fn check_fn_attrs(&mut self, fn_def_id: LocalDefId) {
+        let cx = self.cx;
+        let attrs = cx.tcx.hir().attrs(cx.tcx.local_def_id_to_hir_id(fn_def_id));
+        let fn_name = cx.tcx.item_name(fn_def_id.to_def_id());
+
+        for attr in get_attr(cx.sess(), attrs, "may_mutate") {
+            let self_adt = cx
+                .tcx
+                .impl_of_method(fn_def_id.to_def_id())
+                .and_then(|impl_id| cx.tcx.type_of(impl_id).instantiate_identity().ty_adt_def())
+                .filter(|adt| adt.is_struct());
+
+            for (field_name, name_span) in attr_names(attr) {
+                let field = self_adt.and_then(|adt| {
+                    adt.non_enum_variant()
+                        .fields
+                        .iter()
+                        .find(|field| field.name.as_str() == field_name)
+                        .and_then(|field| field.did.as_local())
+                });
+                if let Some(field) = field {
+                    if !self.is_checked(field) {
+                        continue;
+                    }
+                    self.restrictions
+                        .allow(field, [(fn_name.to_string(), name_span)], Vec::new());
+                    self.declared_mutations
+                        .entry(fn_def_id)
+                        .or_default()
+                        .push((field, name_span));
+                } else {
+                    emit(
+                        cx,
+                        self.as_error,
+                        cx.tcx.local_def_id_to_hir_id(fn_def_id),
+                        name_span,
+                        format!("`{field_name}` is not a field of the `Self` type of `{fn_name}`"),
+                        |_| {},
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Collects the attributes restricting a field to the listed functions. The configured name is
+/// either the name of a Clippy attribute or the full path of a tool attribute, e.g.
+/// `my_tool::mutatedby`.
+fn restriction_attrs<'a>(sess: &'a Session, attrs: &'a [Attribute], name: &'static str) -> Vec<&'a Attribute> {
+    if name.contains("::") {
+        attrs
+            .iter()
+            .filter(|attr| {
+                if let AttrKind::Normal(normal_attr) = &attr.kind {
+                    normal_attr
+                        .item
+                        .path
+                        .segments
+                        .iter()
+                        .map(|segment| segment.ident.name.as_str())
+                        .eq(name.split("::"))
+                } else {
+                    false
+                }
+            })
+            .collect()
+    } else {
+        get_attr(sess, attrs, name).collect()
+    }
+}
+
+/// Whether the function has a `#[clippy::dump_mutations]` attribute, which prints whether its
+/// body was checked and the restricted items it mutates. This is intended for debugging.
+fn has_dump_attr(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    let attrs = cx.tcx.hir().attrs(cx.tcx.local_def_id_to_hir_id(def_id));
+    get_attr(cx.sess(), attrs, "dump_mutations").next().is_some()
+}
+
+fn attribute_path(name: &str) -> Cow<'_, str> {
+    if name.contains("::") {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(format!("clippy::{name}"))
+    }
+}
+
+/// A name listed in an attribute, along with the span of its token.
+type AttrEntry = (String, Span);
+
+/// Collects the names listed in a `#[clippy::mutatedby(...)]` or `#[clippy::may_mutate(...)]`
+/// attribute along with the span of each name. Names can be given either as string literals or as
+/// plain identifiers.
+fn attr_names(attr: &Attribute) -> Vec<AttrEntry> {
+    attr_entries(attr).0
+}
+
+/// Collects the entries of a `#[clippy::mutatedby(...)]` or `#[clippy::not_mutatedby(...)]`
+/// attribute, split into the function names and the types given as `impl = "MyStruct"`. Each entry
+/// comes with the span of its token, so diagnostics can point to a single entry.
+fn attr_entries(attr: &Attribute) -> (Vec<AttrEntry>, Vec<AttrEntry>) {
+    let mut names = Vec::new();
+    let mut impls = Vec::new();
+    if let AttrKind::Normal(normal_attr) = &attr.kind
+        && let AttrArgs::Delimited(delimited) = &normal_attr.item.args
+    {
+        let str_lit = |tt: Option<&TokenTree>| match tt {
+            Some(TokenTree::Token(
+                Token {
+                    kind: TokenKind::Literal(lit),
+                    span,
+                },
+                _,
+            )) if lit.kind == LitKind::Str => Some((lit.symbol.to_string(), *span)),
+            _ => None,
+        };
+        let mut trees = delimited.tokens.trees();
+        while let Some(tt) = trees.next() {
+            match tt {
+                TokenTree::Token(
+                    Token {
+                        kind: TokenKind::Ident(kw::Impl, IdentIsRaw::No),
+                        ..
+                    },
+                    _,
+                ) => {
+                    if let Some(TokenTree::Token(
+                        Token {
+                            kind: TokenKind::Eq, ..
+                        },
+                        _,
+                    )) = trees.next()
+                        && let Some(ty) = str_lit(trees.next())
+                    {
+                        impls.push(ty);
                     }
+                },
+                TokenTree::Token(
+                    Token {
+                        kind: TokenKind::Ident(ident, _),
+                        span,
+                    },
+                    _,
+                ) => names.push((ident.to_string(), *span)),
+                _ => names.extend(str_lit(Some(tt))),
+            }
+        }
+    }
+    (names, impls)
+}
+
+/// The entries of the restriction attributes of an item, along with the span of each entry.
+type Entries = FxIndexMap<String, Span>;
+
+/// Adds the entries, keeping the span of the first occurrence of an entry listed several times.
+fn add_entries(entries: &mut Entries, new: impl IntoIterator<Item = AttrEntry>) {
+    for (name, span) in new {
+        entries.entry(name).or_insert(span);
+    }
+}
+
+/// Finds the first entry matching one of the paths.
+fn matching_entry(entries: Option<&Entries>, paths: &[Option<String>]) -> Option<AttrEntry> {
+    let entries = entries?;
+    paths.iter().flatten().find_map(|path| {
+        entries
+            .iter()
+            .find(|(pattern, _)| whitelist_matches(pattern, path))
+            .map(|(pattern, &span)| (pattern.clone(), span))
+    })
+}
+
+/// The paths restriction entries are matched against: the function's definition path and its
+/// name qualified by the `Self` type of its impl, if any. The path of the `Self` type is returned
+/// on its own for `impl = ".."` entries.
+fn fn_paths(cx: &LateContext<'_>, fn_def_id: LocalDefId) -> ([Option<String>; 2], [Option<String>; 1]) {
+    let self_ty_path = self_ty_path(cx, fn_def_id);
+    let paths = [
+        Some(cx.tcx.def_path_str(fn_def_id)),
+        self_ty_path
+            .as_ref()
+            .map(|self_ty_path| format!("{self_ty_path}::{}", cx.tcx.item_name(fn_def_id.to_def_id()))),
+    ];
+    (paths, [self_ty_path])
+}
+
+/// Records every place projecting onto a restricted field that gets mutated or mutably borrowed.
+struct MutationDelegate<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    restrictions: &'a Restrictions,
+    /// The mutated field along with the expression or pattern binding mutating it.
+    mutations: Vec<(LocalDefId, HirId)>,
+}
+
+impl<'a, 'tcx> MutationDelegate<'a, 'tcx> {
+    fn record(&mut self, cmt: &PlaceWithHirId<'_>, diag_expr_id: HirId) {
+        // A `ref mut` binding in a pattern, e.g. `let Self { ref mut field, .. } = *self;`, is
+        // reported on the binding rather than on the destructured expression. The place then
+        // belongs to the pattern containing the binding.
+        let pat = if let Node::Pat(pat) = self.cx.tcx.hir_node(cmt.hir_id) {
+            Some(pat)
+        } else {
+            None
+        };
+        let place = &cmt.place;
+        for (i, projection) in place.projections.iter().enumerate() {
+            if let ProjectionKind::Field(field_idx, variant_idx) = projection.kind
+                && let ty::Adt(adt, _) = place.ty_before_projection(i).kind()
+                && let variant = adt.variant(variant_idx)
+                && let Some(field) = variant.fields[field_idx].did.as_local()
+                && self.restrictions.is_restricted(field)
+            {
+                let hir_id = match pat {
+                    Some(pat) if i + 1 == place.projections.len() => {
+                        field_subpattern(self.cx, pat, field_idx, variant.fields.len()).unwrap_or(pat.hir_id)
+                    },
+                    Some(pat) => pat.hir_id,
+                    None => diag_expr_id,
+                };
+                if !self.mutations.contains(&(field, hir_id)) {
+                    self.mutations.push((field, hir_id));
                 }
             }
         }
     }
 }
+
+/// Finds the subpattern of a struct or tuple struct pattern binding the field at `field_idx`.
+fn field_subpattern(cx: &LateContext<'_>, pat: &Pat<'_>, field_idx: FieldIdx, field_count: usize) -> Option<HirId> {
+    match pat.kind {
+        PatKind::Struct(_, fields, _) => fields
+            .iter()
+            .find(|field| cx.typeck_results().opt_field_index(field.hir_id) == Some(field_idx))
+            .map(|field| field.pat.hir_id),
+        PatKind::TupleStruct(_, pats, dotdot) => {
+            let idx = field_idx.as_usize();
+            let pat_idx = match dotdot.as_opt_usize() {
+                Some(dotdot) if idx >= dotdot => idx.checked_sub(field_count - pats.len()).filter(|&i| i >= dotdot)?,
+                _ => idx,
+            };
+            pats.get(pat_idx).map(|pat| pat.hir_id)
+        },
+        _ => None,
+    }
+}
+
+impl<'tcx> Delegate<'tcx> for MutationDelegate<'_, 'tcx> {
+    fn consume(&mut self, _: &PlaceWithHirId<'tcx>, _: HirId) {}
+
+    fn borrow(&mut self, cmt: &PlaceWithHirId<'tcx>, diag_expr_id: HirId, bk: BorrowKind) {
+        if bk != BorrowKind::ImmBorrow {
+            self.record(cmt, diag_expr_id);
+        }
+    }
+
+    fn mutate(&mut self, cmt: &PlaceWithHirId<'tcx>, diag_expr_id: HirId) {
+        self.record(cmt, diag_expr_id);
+    }
+
+    fn fake_read(&mut self, _: &PlaceWithHirId<'tcx>, _: FakeReadCause, _: HirId) {}
+}
+
+/// Checks whether `pattern`, an entry of a `#[clippy::mutatedby(..)]` or
+/// `#[clippy::not_mutatedby(..)]` attribute, matches the path of a function, e.g.
+/// `MyStruct::set`.
+///
+/// Both are split into `::` separated segments, which are compared from the end. The pattern may
+/// leave out leading segments, so `set` and `MyStruct::set` both match `my_mod::MyStruct::set`,
+/// unless it starts with `crate`, which anchors it at the crate root.
+/// Within a segment, `*` matches any sequence of characters and `?` matches a single character.
+/// Wildcards never match across segments, except for a final segment consisting of just `*`,
+/// which matches any number of remaining segments, so `crate::setters::*` matches every function
+/// in the `setters` module and its submodules. A pattern consisting of just `*` matches every
+/// function.
+pub fn whitelist_matches(pattern: &str, candidate: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+    let split = |s: &str| -> Vec<Vec<char>> { s.split("::").map(|segment| segment.chars().collect()).collect() };
+    let mut pattern = split(pattern);
+    let candidate = split(candidate);
+    let is_anchored = pattern.len() > 1 && pattern[0].iter().copied().eq("crate".chars());
+    if is_anchored {
+        pattern.remove(0);
+    }
+    let segments_match = |pattern: &[Vec<char>], candidate: &[Vec<char>]| {
+        pattern
+            .iter()
+            .zip(candidate)
+            .all(|(pattern, segment)| segment_matches(pattern, segment))
+    };
+    if let [prefix @ .., last] = &pattern[..]
+        && *last == ['*']
+    {
+        // The prefix has to be followed by at least one segment
+        let mut starts = if is_anchored {
+            0..1
+        } else {
+            0..candidate.len().saturating_sub(prefix.len())
+        };
+        prefix.len() < candidate.len() && starts.any(|start| segments_match(prefix, &candidate[start..]))
+    } else if is_anchored {
+        pattern.len() == candidate.len() && segments_match(&pattern, &candidate)
+    } else {
+        pattern.len() <= candidate.len() && segments_match(&pattern, &candidate[candidate.len() - pattern.len()..])
+    }
+}
+
+fn segment_matches(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|start| segment_matches(rest, &text[start..])),
+        Some(('?', rest)) => text.split_first().is_some_and(|(_, text)| segment_matches(rest, text)),
+        Some((c, rest)) => text
+            .split_first()
+            .is_some_and(|(t, text)| t == c && segment_matches(rest, text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{whitelist_matches, FieldsMutatedByWhitelist};
+    use clippy_config::Conf;
+    use rustc_hir::def_id::{DefId, DefIndex, LocalDefId, LOCAL_CRATE};
+    use rustc_span::{create_default_session_globals_then, Symbol, DUMMY_SP};
+
+    fn matches(pattern: &str, candidate: &str) -> bool {
+        whitelist_matches(pattern, candidate)
+    }
+
+    #[test]
+    fn exact() {
+        assert!(matches("set", "set"));
+        assert!(!matches("set", "reset"));
+        assert!(!matches("set", "set_value"));
+        assert!(!matches("", "set"));
+        assert!(!matches("Set", "set"));
+    }
+
+    #[test]
+    fn star() {
+        assert!(matches("*", "set"));
+        assert!(matches("*", "my_mod::MyStruct::set"));
+        assert!(matches("set_*", "set_value"));
+        assert!(matches("set_*", "set_"));
+        assert!(matches("*_value", "set_value"));
+        assert!(matches("s*t*e", "set_value"));
+        assert!(!matches("set_*", "set"));
+        assert!(!matches("set_*", "get_value"));
+    }
+
+    #[test]
+    fn question_mark() {
+        assert!(matches("?et", "set"));
+        assert!(matches("set_?", "set_x"));
+        assert!(!matches("set_?", "set_"));
+        assert!(!matches("set_?", "set_xy"));
+        assert!(matches("?et_*", "get_value"));
+    }
+
+    #[test]
+    fn qualified() {
+        assert!(matches("set", "my_mod::MyStruct::set"));
+        assert!(matches("MyStruct::set", "my_mod::MyStruct::set"));
+        assert!(matches("my_mod::MyStruct::set", "my_mod::MyStruct::set"));
+        assert!(!matches("Other::set", "my_mod::MyStruct::set"));
+        assert!(!matches("MyStruct::set", "set"));
+        assert!(!matches("other::my_mod::MyStruct::set", "my_mod::MyStruct::set"));
+        assert!(!matches("Struct::set", "my_mod::MyStruct::set"));
+    }
+
+    #[test]
+    fn qualified_wildcards() {
+        assert!(matches("MyStruct::*", "my_mod::MyStruct::set"));
+        assert!(matches("*::set", "my_mod::MyStruct::set"));
+        assert!(matches("My*::set", "my_mod::MyStruct::set"));
+        assert!(!matches("MyStruct::*", "my_mod::Other::set"));
+        // Wildcards stay within their segment
+        assert!(!matches("my_mod*set", "my_mod::MyStruct::set"));
+        assert!(!matches("*::set", "set"));
+    }
+
+    #[test]
+    fn path_prefix() {
+        assert!(matches("setters::*", "setters::set"));
+        assert!(matches("setters::*", "my_mod::setters::set"));
+        assert!(matches("setters::*", "setters::MyStruct::set"));
+        assert!(matches("my_mod::*", "my_mod::setters::MyStruct::set"));
+        assert!(!matches("setters::*", "setters"));
+        assert!(!matches("setters::*", "getters::set"));
+        assert!(!matches("setters::*", "my_mod::set"));
+    }
+
+    #[test]
+    fn crate_anchored() {
+        assert!(matches("crate::setters::*", "setters::set"));
+        assert!(matches("crate::setters::*", "setters::inner::MyStruct::set"));
+        assert!(!matches("crate::setters::*", "my_mod::setters::set"));
+        assert!(matches("crate::*", "set"));
+        assert!(matches("crate::my_mod::set", "my_mod::set"));
+        assert!(!matches("crate::set", "my_mod::set"));
+        assert!(!matches("crate::my_mod::set", "other::my_mod::set"));
+    }
+
+    #[test]
+    fn protected_fields() {
+        create_default_session_globals_then(|| {
+            let mut pass = FieldsMutatedByWhitelist::new(Box::leak(Box::new(Conf::default())));
+            let ty = DefId {
+                krate: LOCAL_CRATE,
+                index: DefIndex::from_u32(1),
+            };
+            let other_ty = DefId {
+                krate: LOCAL_CRATE,
+                index: DefIndex::from_u32(2),
+            };
+            let field = |index| LocalDefId {
+                local_def_index: DefIndex::from_u32(index),
+            };
+            for (owner, name, index, allowed) in [
+                (ty, "count", 3, "increment"),
+                (ty, "total", 4, "reset"),
+                (other_ty, "flag", 5, "set"),
+            ] {
+                pass.restrictions.restrict(field(index), DUMMY_SP);
+                pass.restrictions
+                    .allow(field(index), [(allowed.to_owned(), DUMMY_SP)], Vec::new());
+                pass.restrictions
+                    .record_field(owner, Symbol::intern(name), field(index));
+            }
+
+            let fields: Vec<_> = pass
+                .protected_fields(ty)
+                .map(|(name, allowed)| (name.to_string(), allowed.keys().cloned().collect::<Vec<_>>()))
+                .collect();
+            assert_eq!(
+                fields,
+                [
+                    ("count".to_owned(), vec!["increment".to_owned()]),
+                    ("total".to_owned(), vec!["reset".to_owned()]),
+                ]
+            );
+            assert_eq!(pass.protected_fields(other_ty).count(), 1);
+            assert_eq!(
+                pass.protected_fields(DefId {
+                    krate: LOCAL_CRATE,
+                    index: DefIndex::from_u32(6),
+                })
+                .count(),
+                0
+            );
+        });
+    }
+}
@@ -9,10 +9,11 @@ use rustc_ast::{
     FormatTrait,
 };
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, Impl, Item, ItemKind};
+use rustc_hir::{Expr, HirId, Impl, Item, ItemKind};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_session::impl_lint_pass;
 use rustc_span::{sym, BytePos, Span};
+use std::iter;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -108,8 +109,11 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usage of `Debug` formatting. The purpose of this
-    /// lint is to catch debugging remnants.
+    /// Checks for usage of `Debug` formatting in `print!`, `write!` and `format!` and their
+    /// variants. The purpose of this lint is to catch debugging remnants.
+    ///
+    /// `Debug` formatting in tests, in code behind `#[cfg(debug_assertions)]` and in `Debug`
+    /// implementations is allowed.
     ///
     /// ### Why restrict this?
     /// The purpose of the `Debug` trait is to facilitate debugging Rust code,
@@ -121,6 +125,11 @@ declare_clippy_lint! {
     /// # let foo = "bar";
     /// println!("{:?}", foo);
     /// ```
+    /// Use instead:
+    /// ```no_run
+    /// # let foo = "bar";
+    /// println!("{}", foo);
+    /// ```
     ///
     /// [stability]: https://doc.rust-lang.org/stable/std/fmt/trait.Debug.html#stability
     #[clippy::version = "pre 1.29.0"]
@@ -307,7 +316,7 @@ impl<'tcx> LateLintPass<'tcx> for Write {
             sym::eprint_macro | sym::eprintln_macro if !allowed_in_tests => {
                 span_lint(cx, PRINT_STDERR, macro_call.span, format!("use of `{name}!`"));
             },
-            sym::write_macro | sym::writeln_macro => {},
+            sym::write_macro | sym::writeln_macro | sym::format_macro => {},
             _ => return,
         }
 
@@ -327,9 +336,11 @@ impl<'tcx> LateLintPass<'tcx> for Write {
                 _ => {},
             }
 
-            check_literal(cx, format_args, name);
+            if diag_name != sym::format_macro {
+                check_literal(cx, format_args, name);
+            }
 
-            if !self.in_debug_impl {
+            if !self.in_debug_impl && !is_in_test(cx.tcx, expr.hir_id) && !is_in_cfg_debug_assertions(cx, expr.hir_id) {
                 for piece in &format_args.template {
                     if let &FormatArgsPiece::Placeholder(FormatPlaceholder {
                         span: Some(span),
@@ -337,7 +348,10 @@ impl<'tcx> LateLintPass<'tcx> for Write {
                         ..
                     }) = piece
                     {
-                        span_lint(cx, USE_DEBUG, span, "use of `Debug`-based formatting");
+                        span_lint_and_then(cx, USE_DEBUG, span, "use of `Debug`-based formatting", |diag| {
+                            diag.help("use `Display` formatting instead, e.g. `{}`");
+                            diag.note("the type may need a `Display` implementation");
+                        });
                     }
                 }
             }
@@ -345,6 +359,19 @@ impl<'tcx> LateLintPass<'tcx> for Write {
     }
 }
 
+/// Checks if the node or any of its parents has a `#[cfg(debug_assertions)]` attribute.
+fn is_in_cfg_debug_assertions(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    iter::once(hir_id)
+        .chain(cx.tcx.hir().parent_id_iter(hir_id))
+        .flat_map(|id| cx.tcx.hir().attrs(id))
+        .any(|attr| {
+            attr.has_name(sym::cfg)
+                && attr
+                    .meta_item_list()
+                    .is_some_and(|items| matches!(&*items, [item] if item.has_name(sym::debug_assertions)))
+        })
+}
+
 fn is_debug_impl(cx: &LateContext<'_>, item: &Item<'_>) -> bool {
     if let ItemKind::Impl(Impl {
         of_trait: Some(trait_ref),
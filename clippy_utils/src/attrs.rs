@@ -26,8 +26,12 @@ pub const BUILTIN_ATTRIBUTES: &[(&str, DeprecationStatus)] = &[
     ("cognitive_complexity",  DeprecationStatus::None),
     ("cyclomatic_complexity", DeprecationStatus::Replaced("cognitive_complexity")),
     ("dump",                  DeprecationStatus::None),
+    ("dump_mutations",        DeprecationStatus::None),
     ("msrv",                  DeprecationStatus::None),
     ("has_significant_drop",  DeprecationStatus::None),
+    ("mutatedby",             DeprecationStatus::None),
+    ("may_mutate",            DeprecationStatus::None),
+    ("not_mutatedby",         DeprecationStatus::None),
 ];
 
 pub struct LimitStack {
@@ -10,7 +10,8 @@
 
 use rustc_errors::{Applicability, Diag, DiagMessage, MultiSpan, SubdiagMessage};
 use rustc_hir::HirId;
-use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_lint::{LateContext, Level, Lint, LintContext};
+use rustc_middle::lint::lint_level;
 use rustc_span::Span;
 use std::env;
 
@@ -283,6 +284,28 @@ pub fn span_lint_hir_and_then(
     });
 }
 
+/// Same as [`span_lint_hir_and_then`], but emits the lint as an error wherever it would otherwise
+/// be emitted as a warning.
+///
+/// Other lint levels are left untouched, so the lint can still be silenced with `#[allow]` or
+/// `#[expect]`, and a `forbid` level is kept as is.
+pub fn span_lint_hir_and_then_as_error(
+    cx: &LateContext<'_>,
+    lint: &'static Lint,
+    hir_id: HirId,
+    sp: impl Into<MultiSpan>,
+    msg: impl Into<DiagMessage>,
+    f: impl FnOnce(&mut Diag<'_, ()>),
+) {
+    let (level, src) = cx.tcx.lint_level_at_node(lint, hir_id);
+    let level = if level == Level::Warn { Level::Deny } else { level };
+    lint_level(cx.sess(), lint, level, src, Some(sp.into()), |diag| {
+        diag.primary_message(msg);
+        f(diag);
+        docs_link(diag, lint);
+    });
+}
+
 /// Add a span lint with a suggestion on how to fix it.
 ///
 /// These suggestions can be parsed by rustfix to allow it to automatically fix your code.
@@ -0,0 +1,47 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct State {
+    #[clippy::mutatedby("init")]
+    ready: bool,
+    count: u32,
+}
+
+impl State {
+    fn init(&mut self) {
+        self.helper();
+        reset(self);
+        let mut bump = || self.in_closure();
+        bump();
+    }
+
+    fn helper(&mut self) {
+        self.ready = true;
+        self.nested();
+    }
+
+    fn in_closure(&mut self) {
+        self.ready = false;
+    }
+
+    // Only one call level is allowed
+    fn nested(&mut self) {
+        self.ready = false;
+        //~^ ERROR: field `ready` is mutated by `nested`, which is not allowed to mutate it
+    }
+
+    fn unrelated(&mut self) {
+        self.ready = true;
+        //~^ ERROR: field `ready` is mutated by `unrelated`, which is not allowed to mutate it
+        self.count += 1;
+    }
+}
+
+fn reset(state: &mut State) {
+    state.ready = false;
+}
+
+fn main() {
+    let mut state = State { ready: false, count: 0 };
+    state.init();
+    state.unrelated();
+}
@@ -0,0 +1,21 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct Connection {
+    #[clippy::mutatedby("open")]
+    open: bool,
+}
+
+impl Connection {
+    fn open(&mut self) {
+        self.open = true;
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.open = false;
+        //~^ ERROR: field `open` is mutated by `drop`
+    }
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+//@no-rustfix
+//@compile-flags: --test
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    field: u8,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        self.field = 1;
+    }
+
+    fn disallowed_function(&mut self) {
+        self.field = 2;
+        //~^ ERROR: field `field` is mutated by `disallowed_function`
+    }
+}
+
+pub struct Denied {
+    #[clippy::not_mutatedby("denied_test")]
+    field: u8,
+}
+
+#[test]
+fn test_mutator() {
+    let mut value = TestStruct { field: 0 };
+    value.field = 3;
+    assert_eq!(value.field, 3);
+}
+
+fn main() {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Denied, TestStruct};
+
+    fn setup(value: &mut TestStruct) {
+        value.field = 4;
+    }
+
+    #[test]
+    fn nested_test() {
+        let mut value = TestStruct { field: 0 };
+        setup(&mut value);
+        value.field += 1;
+    }
+
+    #[test]
+    fn denied_test() {
+        let mut value = Denied { field: 0 };
+        value.field = 5;
+        //~^ ERROR: field `field` is mutated by `denied_test`
+    }
+}
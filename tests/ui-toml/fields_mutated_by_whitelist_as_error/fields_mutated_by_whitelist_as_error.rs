@@ -0,0 +1,40 @@
+// Overrides the `-D warnings` the tests are run with, so that the lint would be a warning
+#![warn(warnings)]
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("setter")]
+    field: u8,
+}
+
+impl TestStruct {
+    fn setter(&mut self) {
+        self.field = 1;
+    }
+
+    fn other(&mut self) {
+        self.field = 2;
+        //~^ ERROR: field `field` is mutated by `other`, which is not allowed to mutate it
+    }
+
+    // a `#[warn]` does not lower the lint back to a warning
+    #[warn(clippy::fields_mutated_by_whitelist)]
+    fn warned(&mut self) {
+        self.field = 3;
+        //~^ ERROR: field `field` is mutated by `warned`, which is not allowed to mutate it
+    }
+
+    // no lint: allowed
+    #[allow(clippy::fields_mutated_by_whitelist)]
+    fn allowed(&mut self) {
+        self.field = 4;
+    }
+
+    // no lint: expected
+    #[expect(clippy::fields_mutated_by_whitelist)]
+    fn expected(&mut self) {
+        self.field = 5;
+    }
+}
+
+fn main() {}
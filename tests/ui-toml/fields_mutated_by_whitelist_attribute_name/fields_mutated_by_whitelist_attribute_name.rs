@@ -0,0 +1,24 @@
+#![feature(register_tool)]
+#![register_tool(my_tool)]
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[my_tool::mutatedby("allowed_function")]
+    field: u8,
+    #[my_tool::other("allowed_function")]
+    other: u8,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        self.field = 1;
+    }
+
+    fn disallowed_function(&mut self) {
+        self.field = 2;
+        //~^ ERROR: field `field` is mutated by `disallowed_function`, which is not allowed to mutate it
+        self.other = 2;
+    }
+}
+
+fn main() {}
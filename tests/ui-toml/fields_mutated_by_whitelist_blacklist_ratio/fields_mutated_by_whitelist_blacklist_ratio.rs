@@ -0,0 +1,26 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct Counter {
+    #[clippy::mutatedby("increment", "reset")]
+    //~^ ERROR: field `count` is mutated by 2 of the 3 methods of `Counter`
+    count: u32,
+    #[clippy::mutatedby("increment")]
+    step: u32,
+}
+
+impl Counter {
+    fn increment(&mut self) {
+        self.count += self.step;
+        self.step += 1;
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    fn get(&self) -> u32 {
+        self.count
+    }
+}
+
+fn main() {}
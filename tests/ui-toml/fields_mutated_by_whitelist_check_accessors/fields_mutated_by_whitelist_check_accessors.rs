@@ -0,0 +1,55 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+#![allow(static_mut_refs)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("field_mut", "setter", "global_field")]
+    field: u8,
+}
+
+fn launder(ts: &mut TestStruct) {
+    *ts.field_mut() = 1;
+    //~^ ERROR: field `field` is mutated by `launder` through `field_mut`
+    *ts.field_mut() += 1;
+    //~^ ERROR: field `field` is mutated by `launder` through `field_mut`
+    *TestStruct::field_mut(ts) = 1;
+    //~^ ERROR: field `field` is mutated by `launder` through `field_mut`
+}
+
+impl TestStruct {
+    fn field_mut(&mut self) -> &mut u8 {
+        &mut self.field
+    }
+
+    fn setter(&mut self) {
+        *self.field_mut() = 2;
+    }
+
+    fn disallowed_mut(&mut self) -> &mut u8 {
+        &mut self.field
+        //~^ ERROR: field `field` is mutated by `disallowed_mut`, which is not allowed to mutate it
+    }
+
+    fn reads(&mut self) -> u8 {
+        *self.field_mut()
+    }
+}
+
+fn through_disallowed(ts: &mut TestStruct) {
+    // Already reported in `disallowed_mut`
+    *ts.disallowed_mut() = 3;
+}
+
+static mut GLOBAL: TestStruct = TestStruct { field: 0 };
+
+fn global_field() -> &'static mut u8 {
+    unsafe { &mut GLOBAL.field }
+}
+
+// Doesn't mention `TestStruct`
+fn launder_global() {
+    *global_field() = 4;
+    //~^ ERROR: field `field` is mutated by `launder_global` through `global_field`
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+#![allow(clippy::transmute_ptr_to_ref)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    field: u8,
+    other: u8,
+}
+
+impl TestStruct {
+    fn allowed_function(&self) {
+        let field: &mut u8 = unsafe { std::mem::transmute(&self.field as *const u8) };
+        *field = 1;
+    }
+
+    fn disallowed_function(&self) {
+        let field: &mut u8 = unsafe { std::mem::transmute(&self.field as *const u8) };
+        //~^ ERROR: `disallowed_function` transmutes to a `&mut` which may alias field `field`
+        *field = 1;
+    }
+
+    fn unrelated_reference(&self, ptr: *mut u8) -> u8 {
+        let value: &mut u8 = unsafe { std::mem::transmute(ptr) };
+        //~^ ERROR: `unrelated_reference` transmutes to a `&mut` which may alias field `field`
+        *value = 1;
+        self.field
+    }
+
+    fn other_field(&self) {
+        let other: &mut u8 = unsafe { std::mem::transmute(&self.other as *const u8) };
+        *other = 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct Counter {
+    #[clippy::mutatedby("increment")]
+    //~^ ERROR: field `count` is mutated by 3 functions which are not allowed to mutate it
+    count: u32,
+    #[clippy::mutatedby("rename")]
+    //~^ ERROR: field `name` is mutated by `reset`, which is not allowed to mutate it
+    name: String,
+    #[clippy::not_mutatedby("reset")]
+    //~^ ERROR: field `total` is mutated by `reset`, which is not allowed to mutate it
+    total: u32,
+}
+
+impl Counter {
+    fn increment(&mut self) {
+        self.count += 1;
+        self.total += 1;
+    }
+
+    fn rename(&mut self, name: &str) {
+        self.name = name.to_owned();
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.name.clear();
+        self.total = 0;
+    }
+
+    fn double(&mut self) {
+        self.count *= 2;
+        self.count += 1;
+    }
+
+    #[allow(clippy::fields_mutated_by_whitelist)]
+    fn allowed(&mut self) {
+        self.count = 10;
+    }
+}
+
+fn decrement(counter: &mut Counter) {
+    counter.count -= 1;
+}
+
+fn main() {}
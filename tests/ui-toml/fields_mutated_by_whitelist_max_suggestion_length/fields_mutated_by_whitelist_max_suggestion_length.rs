@@ -0,0 +1,25 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("set")]
+    short: u8,
+    #[clippy::mutatedby("set", "reset")]
+    long: u8,
+}
+
+impl TestStruct {
+    fn set(&mut self) {
+        self.short = 1;
+        self.long = 1;
+    }
+
+    fn update(&mut self) {
+        self.short = 2;
+        //~^ ERROR: field `short` is mutated by `update`
+        self.long = 2;
+        //~^ ERROR: field `long` is mutated by `update`
+    }
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+mod inner {
+    pub struct TestStruct {
+        #[clippy::mutatedby("allowed_function")]
+        private_field: u8,
+        #[clippy::mutatedby("allowed_function")]
+        pub public_field: u8,
+        #[clippy::mutatedby("allowed_function")]
+        pub(crate) crate_field: u8,
+    }
+
+    impl TestStruct {
+        fn allowed_function(&mut self) {
+            self.private_field = 1;
+            self.public_field = 1;
+            self.crate_field = 1;
+        }
+
+        fn disallowed_function(&mut self) {
+            self.private_field = 2;
+            self.public_field = 2;
+            //~^ ERROR: field `public_field` is mutated by `disallowed_function`
+            self.crate_field = 2;
+            //~^ ERROR: field `crate_field` is mutated by `disallowed_function`
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+mod inner {
+    pub struct TestStruct {
+        #[clippy::mutatedby("setter")]
+        pub annotated: u8,
+        #[clippy::mutatedby("*")]
+        pub free: u8,
+        pub unannotated: u8,
+        //~^ ERROR: field `unannotated` is visible outside of its module
+        pub(crate) crate_unannotated: u8,
+        //~^ ERROR: field `crate_unannotated` is visible outside of its module
+        private: u8,
+        #[allow(clippy::fields_mutated_by_whitelist)]
+        pub allowed: u8,
+    }
+
+    impl TestStruct {
+        fn setter(&mut self) {
+            self.annotated = 1;
+        }
+
+        fn anything(&mut self) {
+            self.free = 2;
+            self.unannotated = 2;
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+mod counter {
+    pub struct Counter {
+        #[clippy::mutatedby("increment")]
+        pub count: u32,
+    }
+
+    impl Counter {
+        pub fn increment(&mut self) {
+            self.count += 1;
+        }
+
+        pub fn reset(&mut self) {
+            self.count = 0;
+            //~^ ERROR: field `count` is mutated by `reset`
+        }
+    }
+
+    pub fn set(counter: &mut Counter, count: u32) {
+        counter.count = count;
+        //~^ ERROR: field `count` is mutated by `set`
+    }
+
+    pub mod nested {
+        // A child module is a different module
+        pub fn clear(counter: &mut super::Counter) {
+            counter.count = 0;
+        }
+    }
+}
+
+// Outside of the defining module, mutations aren't checked
+fn overwrite(counter: &mut counter::Counter) {
+    counter.count = 10;
+}
+
+fn main() {
+    let mut counter = counter::Counter { count: 0 };
+    counter.increment();
+    counter.count = 5;
+    overwrite(&mut counter);
+}
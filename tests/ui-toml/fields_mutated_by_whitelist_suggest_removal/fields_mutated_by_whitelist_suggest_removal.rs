@@ -0,0 +1,31 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("setter")]
+    field: u8,
+}
+
+impl TestStruct {
+    fn setter(&mut self) {
+        self.field = 1;
+    }
+
+    fn in_branch(&mut self, reset: bool) {
+        if reset {
+            self.field = 0;
+            //~^ ERROR: field `field` is mutated by `in_branch`, which is not allowed to mutate it
+        }
+    }
+
+    fn in_arm(&mut self, value: Option<u8>) {
+        match value {
+            Some(value) => {
+                self.field = value;
+                //~^ ERROR: field `field` is mutated by `in_arm`, which is not allowed to mutate it
+            },
+            None => self.setter(),
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("setter")]
+    field: u8,
+}
+
+impl TestStruct {
+    fn setter(&mut self) {
+        self.field = 1;
+    }
+
+    // no suggestion: not the only statement of its block
+    fn with_others(&mut self) -> u8 {
+        self.field = 2;
+        //~^ ERROR: field `field` is mutated by `with_others`, which is not allowed to mutate it
+        self.field
+    }
+
+    // no suggestion: not a plain assignment
+    fn compound(&mut self) {
+        self.field += 1;
+        //~^ ERROR: field `field` is mutated by `compound`, which is not allowed to mutate it
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#![warn(clippy::bulk_allow_attribute)]
+
+#[allow(clippy::needless_return)]
+fn single() {}
+
+#[allow(clippy::needless_return, clippy::let_and_return)]
+//~^ ERROR: `allow` attribute suppressing 2 Clippy lints at once
+fn two() {}
+
+fn main() {}
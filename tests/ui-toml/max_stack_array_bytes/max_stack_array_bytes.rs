@@ -0,0 +1,11 @@
+#![allow(unused)]
+#![warn(clippy::large_const_arrays, clippy::large_stack_arrays)]
+//@no-rustfix
+// `array-size-threshold` still applies to `large_const_arrays`
+const ABOVE: [u8; 11] = [0; 11];
+
+fn main() {
+    let above = [0u8; 11];
+    //~^ ERROR: allocating a local array larger than 10 bytes
+    let below = [0u8; 10];
+}
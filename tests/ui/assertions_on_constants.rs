@@ -52,6 +52,29 @@ fn main() {
     assert!(8 == (7 + 1));
     //~^ ERROR: `assert!(true)` will be optimized out by the compiler
 
+    assert_eq!(1, 1);
+    //~^ ERROR: this `assert_eq!` always succeeds and will be optimized out by the compiler
+    assert_eq!(true, true, "message");
+    //~^ ERROR: this `assert_eq!` always succeeds and will be optimized out by the compiler
+    assert_ne!("a", "a", "{} message", msg);
+    //~^ ERROR: this `assert_ne!` always fails and should probably be replaced
+    debug_assert_eq!('a', 'a');
+    //~^ ERROR: this `debug_assert_eq!` always succeeds and will be optimized out by the compiler
+    // Don't lint, same as `debug_assert!(false)`
+    debug_assert_ne!(1, 1);
+    // Don't lint different literals or expressions, which are usually there on purpose
+    assert_eq!(1, 2);
+    assert_ne!(1, 2);
+    assert_eq!(2 + 2, 4);
+    // Don't lint named constants, whose value is checked
+    const ANSWER: u32 = 42;
+    assert_eq!(ANSWER, 42);
+    // Don't lint floating point comparisons
+    assert_eq!(0.5, 0.5);
+    // Don't lint non-constant arguments
+    let x = 1;
+    assert_eq!(x, 1);
+
     // Don't lint if the value is dependent on a defined constant:
     const N: usize = 1024;
     const _: () = assert!(N.is_power_of_two());
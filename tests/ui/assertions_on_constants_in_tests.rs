@@ -0,0 +1,22 @@
+//@no-rustfix
+#![warn(clippy::assertions_on_constants)]
+#![allow(clippy::eq_op)]
+
+fn main() {
+    assert_eq!(1, 1);
+    //~^ ERROR: this `assert_eq!` always succeeds and will be optimized out by the compiler
+}
+
+#[test]
+fn pinned_values() {
+    // Don't lint in tests
+    assert_eq!(1, 1);
+    assert_ne!("a", "a");
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() {
+        assert_eq!(true, true);
+    }
+}
@@ -0,0 +1,73 @@
+//! A reduced copy of the `lazy_static` crate, which expands the same way.
+
+use std::cell::Cell;
+use std::sync::Once;
+
+#[doc(hidden)]
+pub use std::ops::Deref as __Deref;
+
+#[doc(hidden)]
+pub struct Lazy<T: Sync>(Cell<Option<T>>, Once);
+
+unsafe impl<T: Sync> Sync for Lazy<T> {}
+
+impl<T: Sync> Lazy<T> {
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub const INIT: Self = Lazy(Cell::new(None), Once::new());
+
+    pub fn get<F: FnOnce() -> T>(&'static self, f: F) -> &T {
+        self.1.call_once(|| self.0.set(Some(f())));
+        unsafe { (*self.0.as_ptr()).as_ref().unwrap() }
+    }
+}
+
+#[macro_export(local_inner_macros)]
+#[doc(hidden)]
+macro_rules! __lazy_static_internal {
+    ($(#[$attr:meta])* ($($vis:tt)*) static ref $N:ident : $T:ty = $e:expr; $($t:tt)*) => {
+        __lazy_static_internal!(@MAKE TY, $(#[$attr])*, ($($vis)*), $N);
+        __lazy_static_internal!(@TAIL, $N : $T = $e);
+        lazy_static!($($t)*);
+    };
+    (@TAIL, $N:ident : $T:ty = $e:expr) => {
+        impl $crate::__Deref for $N {
+            type Target = $T;
+            fn deref(&self) -> &$T {
+                #[inline(always)]
+                fn __static_ref_initialize() -> $T { $e }
+
+                #[inline(always)]
+                fn __stability() -> &'static $T {
+                    static LAZY: $crate::Lazy<$T> = $crate::Lazy::INIT;
+                    LAZY.get(__static_ref_initialize)
+                }
+                __stability()
+            }
+        }
+    };
+    (@MAKE TY, $(#[$attr:meta])*, ($($vis:tt)*), $N:ident) => {
+        #[allow(missing_copy_implementations)]
+        #[allow(non_camel_case_types)]
+        #[allow(dead_code)]
+        $(#[$attr])*
+        $($vis)* struct $N {__private_field: ()}
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        $($vis)* static $N: $N = $N {__private_field: ()};
+    };
+    () => ()
+}
+
+#[macro_export(local_inner_macros)]
+macro_rules! lazy_static {
+    ($(#[$attr:meta])* static ref $N:ident : $T:ty = $e:expr; $($t:tt)*) => {
+        __lazy_static_internal!($(#[$attr])* () static ref $N : $T = $e; $($t)*);
+    };
+    ($(#[$attr:meta])* pub static ref $N:ident : $T:ty = $e:expr; $($t:tt)*) => {
+        __lazy_static_internal!($(#[$attr])* (pub) static ref $N : $T = $e; $($t)*);
+    };
+    ($(#[$attr:meta])* pub ($($vis:tt)+) static ref $N:ident : $T:ty = $e:expr; $($t:tt)*) => {
+        __lazy_static_internal!($(#[$attr])* (pub ($($vis)+)) static ref $N : $T = $e; $($t)*);
+    };
+    () => ()
+}
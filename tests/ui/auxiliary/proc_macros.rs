@@ -34,10 +34,7 @@ fn expect_tt<T>(tt: Option<TT>, f: impl FnOnce(TT) -> Option<T>, expected: &str,
         )),
         Some(tt) => {
             let span = tt.span();
-            match f(tt) {
-                Some(x) => Ok(x),
-                None => Err(make_error(&format!("unexpected token, expected {expected}"), span)),
-            }
+            f(tt).ok_or_else(|| make_error(&format!("unexpected token, expected {expected}"), span))
         },
     }
 }
@@ -0,0 +1,29 @@
+#![warn(clippy::box_pin_unpin_type)]
+#![allow(clippy::let_underscore_future)]
+
+use std::future::{ready, Future};
+use std::pin::Pin;
+
+struct Plain(u32);
+
+fn generic<T: Unpin>(x: T) {
+    let _ = Box::pin(x);
+    //~^ ERROR: pinning a value of type `T`
+}
+
+fn main() {
+    let _ = Box::pin(5);
+    //~^ ERROR: pinning a value of type `i32`
+    let _ = Box::pin(Plain(1));
+    //~^ ERROR: pinning a value of type `Plain`
+    let _ = Box::pin(String::new());
+    //~^ ERROR: pinning a value of type `String`
+
+    // Don't lint
+
+    // Not `Unpin`
+    let _ = Box::pin(async {});
+    // Coerced to a trait object
+    let _: Pin<Box<dyn Future<Output = ()>>> = Box::pin(ready(()));
+    let _ = Box::new(5);
+}
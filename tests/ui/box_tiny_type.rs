@@ -0,0 +1,61 @@
+#![warn(clippy::box_tiny_type)]
+#![allow(clippy::box_default)]
+
+use std::any::Any;
+use std::fmt::Debug;
+
+#[derive(Debug, Default)]
+struct Pair(u8, u8);
+
+struct Empty;
+
+fn takes_dyn(_: Box<dyn Debug>) {}
+
+fn generic<T>(value: T) -> Box<T> {
+    // no lint: the size isn't known
+    Box::new(value)
+}
+
+struct Wrapper {
+    inner: Box<u8>,
+}
+
+fn boxed() -> Box<u8> {
+    Box::new(1u8)
+}
+
+fn main() {
+    let _ = Box::new(1u8);
+    //~^ ERROR: boxing a `u8`, which is smaller than the pointer to it, allocates for no benefit
+    let _ = Box::new(true);
+    //~^ ERROR: boxing a `bool`, which is smaller than the pointer to it, allocates for no benefit
+    let _ = Box::new('a');
+    //~^ ERROR: boxing a `char`, which is smaller than the pointer to it, allocates for no benefit
+    let _ = Box::new(Pair::default());
+    //~^ ERROR: boxing a `Pair`, which is smaller than the pointer to it, allocates for no benefit
+
+    // no lint: the type is fixed by the context
+    let _: Box<u16> = Box::new(Default::default());
+    let _ = Wrapper { inner: Box::new(1u8) };
+    let _ = boxed();
+
+    // no lint: coerced to a trait object
+    let _: Box<dyn Debug> = Box::new(1u8);
+    let _: Box<dyn Any> = Box::new(true);
+    takes_dyn(Box::new('a'));
+
+    // no lint: the box is needed
+    let _ = Box::into_raw(Box::new(1u8));
+    let _ = Box::leak(Box::new(true));
+
+    // no lint: at least as large as a pointer
+    let _ = Box::new(1usize);
+    let _ = Box::new([0u8; 16]);
+    let _ = Box::new(String::new());
+
+    // no lint: zero-sized values aren't allocated
+    let _ = Box::new(());
+    let _ = Box::new(Empty);
+
+    let _ = generic(1u8);
+}
@@ -0,0 +1,85 @@
+#![crate_type = "lib"]
+#![warn(clippy::boxed_error_in_lib_api)]
+
+use std::error::Error;
+use std::fmt;
+
+pub fn parse(input: &str) -> Result<u32, Box<dyn Error>> {
+    //~^ ERROR: exported function returns a `Box<dyn Error>`
+    Ok(input.parse()?)
+}
+
+pub fn parse_send(input: &str) -> Result<u32, Box<dyn std::error::Error + Send + Sync>> {
+    //~^ ERROR: exported function returns a `Box<dyn Error>`
+    Ok(input.parse()?)
+}
+
+pub fn nested(inputs: &[&str]) -> Vec<Result<u32, Box<dyn Error>>> {
+    //~^ ERROR: exported function returns a `Box<dyn Error>`
+    inputs.iter().map(|input| Ok(input.parse()?)).collect()
+}
+
+pub struct Parser;
+
+impl Parser {
+    pub fn parse(&self, input: &str) -> Result<u32, Box<dyn Error>> {
+        //~^ ERROR: exported function returns a `Box<dyn Error>`
+        Ok(input.parse()?)
+    }
+
+    fn private(&self, input: &str) -> Result<u32, Box<dyn Error>> {
+        Ok(input.parse()?)
+    }
+}
+
+pub trait Parse {
+    fn parse(&self, input: &str) -> Result<u32, Box<dyn Error>>;
+    //~^ ERROR: exported function returns a `Box<dyn Error>`
+}
+
+pub trait ParseDefault {
+    fn parse_default(&self, input: &str) -> Result<u32, Box<dyn Error>> {
+        //~^ ERROR: exported function returns a `Box<dyn Error>`
+        Ok(input.parse()?)
+    }
+}
+
+// The signature is dictated by the trait
+impl Parse for Parser {
+    fn parse(&self, input: &str) -> Result<u32, Box<dyn Error>> {
+        Ok(input.parse()?)
+    }
+}
+
+fn private(input: &str) -> Result<u32, Box<dyn Error>> {
+    Ok(input.parse()?)
+}
+
+mod inner {
+    pub fn not_exported(input: &str) -> Result<u32, Box<dyn std::error::Error>> {
+        Ok(input.parse()?)
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseError;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("parse error")
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn concrete(input: &str) -> Result<u32, ParseError> {
+    input.parse().map_err(|_| ParseError)
+}
+
+pub fn boxed_concrete(input: &str) -> Result<u32, Box<ParseError>> {
+    input.parse().map_err(|_| Box::new(ParseError))
+}
+
+pub fn other_trait_object() -> Box<dyn fmt::Debug> {
+    Box::new(1)
+}
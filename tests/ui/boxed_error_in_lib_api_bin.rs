@@ -0,0 +1,14 @@
+//@check-pass
+#![warn(clippy::boxed_error_in_lib_api)]
+
+use std::error::Error;
+
+// Not a library, so there are no callers matching on the errors
+pub fn parse(input: &str) -> Result<u32, Box<dyn Error>> {
+    Ok(input.parse()?)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    parse("1")?;
+    Ok(())
+}
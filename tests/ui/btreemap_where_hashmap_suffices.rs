@@ -0,0 +1,69 @@
+#![warn(clippy::btreemap_where_hashmap_suffices)]
+
+use std::collections::BTreeMap;
+
+fn lookups_only() {
+    let mut map = BTreeMap::new();
+    //~^ ERROR: this `BTreeMap` is never used in a way which depends on the ordering of its keys
+    map.insert("one", 1);
+    *map.entry("two").or_insert(0) += 2;
+    assert!(map.contains_key("one"));
+    assert_eq!(map.get("two"), Some(&2));
+    map.remove("one");
+    assert_eq!(map.len(), 1);
+}
+
+fn annotated() -> Option<u32> {
+    let mut map: BTreeMap<u32, u32> = BTreeMap::default();
+    //~^ ERROR: this `BTreeMap` is never used in a way which depends on the ordering of its keys
+    map.insert(1, 2);
+    map.get(&1).copied()
+}
+
+fn range_query() -> u32 {
+    let mut map = BTreeMap::new();
+    map.insert(1, 2);
+    map.range(0..5).map(|(_, v)| v).sum()
+}
+
+fn first_entry() -> Option<u32> {
+    let mut map = BTreeMap::new();
+    map.insert(1, 2);
+    map.first_key_value().map(|(k, _)| *k)
+}
+
+fn ordered_iteration() {
+    let mut map = BTreeMap::new();
+    map.insert(1, 2);
+    for (k, v) in &map {
+        println!("{k}: {v}");
+    }
+    let _: Vec<_> = map.keys().collect();
+}
+
+fn escapes() -> BTreeMap<u32, u32> {
+    let mut map = BTreeMap::new();
+    map.insert(1, 2);
+    map
+}
+
+fn passed_to_function(f: impl Fn(&BTreeMap<u32, u32>)) {
+    let mut map = BTreeMap::new();
+    map.insert(1, 2);
+    f(&map);
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct NotHash(u32);
+
+fn key_not_hash() {
+    let mut map = BTreeMap::new();
+    map.insert(NotHash(1), 2);
+    map.remove(&NotHash(1));
+}
+
+fn unused() {
+    let _map: BTreeMap<u32, u32> = BTreeMap::new();
+}
+
+fn main() {}
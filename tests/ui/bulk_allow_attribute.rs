@@ -0,0 +1,44 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::bulk_allow_attribute)]
+#![allow(unfulfilled_lint_expectations)]
+
+extern crate proc_macros;
+use proc_macros::{external, with_span};
+
+#[allow(clippy::eq_op, clippy::op_ref, clippy::unit_arg, clippy::identity_op)]
+//~^ ERROR: `allow` attribute suppressing 4 Clippy lints at once
+fn too_many() {}
+
+#[expect(clippy::eq_op, clippy::op_ref, clippy::unit_arg, clippy::identity_op)]
+//~^ ERROR: `expect` attribute suppressing 4 Clippy lints at once
+fn too_many_expect() {}
+
+#[allow(clippy::needless_return, clippy::let_and_return, clippy::unit_arg)]
+fn at_limit() {}
+
+#[allow(
+    clippy::needless_return,
+    clippy::let_and_return,
+    clippy::unit_arg,
+    reason = "the reason isn't counted"
+)]
+fn reason_not_counted() {}
+
+#[allow(dead_code, unused_variables, unused_mut, unreachable_code, clippy::unit_arg)]
+fn rustc_lints_not_counted() {}
+
+#[warn(clippy::eq_op, clippy::op_ref, clippy::unit_arg, clippy::identity_op)]
+fn not_allow() {}
+
+external! {
+    #[allow(clippy::eq_op, clippy::op_ref, clippy::unit_arg, clippy::identity_op)]
+    fn external() {}
+}
+
+with_span! {
+    span
+    #[allow(clippy::eq_op, clippy::op_ref, clippy::unit_arg, clippy::identity_op)]
+    fn from_proc_macro() {}
+}
+
+fn main() {}
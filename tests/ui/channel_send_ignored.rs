@@ -0,0 +1,35 @@
+#![warn(clippy::channel_send_ignored)]
+
+use std::sync::mpsc;
+
+struct Mailbox;
+
+impl Mailbox {
+    fn send(&self, _: u32) {}
+}
+
+fn main() {
+    let (sender, receiver) = mpsc::channel();
+    let _ = sender.send(1);
+    //~^ ERROR: non-binding `let` on the result of a `send` call
+
+    let (sync_sender, _sync_receiver) = mpsc::sync_channel(1);
+    let _ = sync_sender.send(2);
+    //~^ ERROR: non-binding `let` on the result of a `send` call
+
+    let _: Result<(), _> = sender.send(3);
+    //~^ ERROR: non-binding `let` on the result of a `send` call
+
+    // no lint: the result is used
+    sender.send(4).unwrap();
+    if sender.send(5).is_err() {}
+    let _result = sender.send(6);
+
+    // no lint: not a `Result`
+    let _ = Mailbox.send(7);
+
+    // no lint: not a `send` call
+    let _ = sync_sender.try_send(8);
+
+    drop(receiver);
+}
@@ -0,0 +1,38 @@
+#![warn(clippy::chars_collect_to_string)]
+#![allow(clippy::needless_borrow)]
+
+macro_rules! collect_chars {
+    ($s:expr) => {
+        $s.chars().collect::<String>()
+    };
+}
+
+fn main() {
+    let s = "abc";
+    let owned = String::from("abc");
+    let borrowed = &owned;
+
+    let _: String = s.chars().collect();
+    //~^ ERROR: collecting the characters of a string into a `String`
+    let _ = s.chars().collect::<String>();
+    //~^ ERROR: collecting the characters of a string into a `String`
+    let _ = owned.chars().collect::<String>();
+    //~^ ERROR: collecting the characters of a string into a `String`
+    let _ = borrowed.chars().collect::<String>();
+    //~^ ERROR: collecting the characters of a string into a `String`
+    let _ = s[1..].chars().collect::<String>();
+    //~^ ERROR: collecting the characters of a string into a `String`
+    let _ = owned.as_str().chars().collect::<String>();
+    //~^ ERROR: collecting the characters of a string into a `String`
+
+    // Other transformations of the characters
+    let _ = s.chars().rev().collect::<String>();
+    let _ = s.chars().filter(|c| c.is_alphabetic()).collect::<String>();
+    let _ = s.chars().map(|c| c.to_ascii_uppercase()).collect::<String>();
+    let _ = s.chars().collect::<Vec<char>>();
+
+    // `to_owned` would copy the reference
+    let _ = (&s).chars().collect::<String>();
+
+    let _ = collect_chars!(s);
+}
@@ -0,0 +1,31 @@
+#![allow(clippy::bytes_nth, clippy::iter_nth, clippy::needless_borrow)]
+#![warn(clippy::chars_nth_for_ascii)]
+
+fn main() {
+    let s = String::from("String");
+    let _ = s.chars().nth(3);
+    //~^ ERROR: called `.chars().nth()` on a `String`
+    let _ = s.chars().nth(3).unwrap();
+    //~^ ERROR: called `.chars().nth().unwrap()` on a `String`
+    let _ = s[..].chars().nth(s.len() - 1);
+    //~^ ERROR: called `.chars().nth()` on a `str`
+    let _ = "abc".chars().nth(1).unwrap();
+    //~^ ERROR: called `.chars().nth().unwrap()` on a `str`
+    let _ = s.chars().nth(3).unwrap_or('x');
+    //~^ ERROR: called `.chars().nth()` on a `String`
+
+    // The cast needs parentheses
+    let _ = s.chars().nth(0).unwrap().is_alphabetic();
+    //~^ ERROR: called `.chars().nth().unwrap()` on a `String`
+    let _ = s.chars().nth(0).unwrap() < 'b';
+    //~^ ERROR: called `.chars().nth().unwrap()` on a `String`
+    let _ = 'b' < s.chars().nth(0).unwrap();
+    //~^ ERROR: called `.chars().nth().unwrap()` on a `String`
+    let _ = (&s).chars().nth(0).unwrap();
+    //~^ ERROR: called `.chars().nth().unwrap()` on a `String`
+
+    // Not the characters of a string
+    let _ = ['a', 'b'].iter().nth(1);
+    let _ = s.bytes().nth(3);
+    let _ = s.chars().skip(1).nth(3);
+}
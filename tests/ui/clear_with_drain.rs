@@ -1,4 +1,4 @@
-#![allow(unused)]
+#![allow(unused, clippy::for_each_drop)]
 #![warn(clippy::clear_with_drain)]
 
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
@@ -354,4 +354,35 @@ fn binary_heap() {
     heap.drain();
 }
 
+fn discarded_iterator() {
+    // Do lint
+    let mut v = vec![1, 2, 3];
+    let _ = v.drain(..);
+
+    // Do lint
+    let mut v = vec![1, 2, 3];
+    drop(v.drain(..));
+
+    // Do lint
+    let mut v = vec![1, 2, 3];
+    v.drain(..).for_each(|_| {});
+
+    // Do lint
+    let mut s = HashSet::from([1, 2, 3]);
+    let _ = s.drain();
+
+    // Do not lint because only part of the vector is drained
+    let mut v = vec![1, 2, 3];
+    let _ = v.drain(1..);
+    drop(v.drain(1..));
+
+    // Do not lint because the iterator is bound
+    let mut v = vec![1, 2, 3];
+    let _iter = v.drain(..);
+
+    // Do not lint because the elements are used
+    let mut v = vec![1, 2, 3];
+    v.drain(..).for_each(|x| println!("{x}"));
+}
+
 fn main() {}
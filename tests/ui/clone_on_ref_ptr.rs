@@ -0,0 +1,23 @@
+#![warn(clippy::clone_on_ref_ptr)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+trait SomeTrait {}
+struct SomeImpl;
+impl SomeTrait for SomeImpl {}
+
+fn main() {
+    let rc = Rc::new(true);
+    let _ = rc.clone();
+
+    let arc = Arc::new(vec![1]);
+    let _ = arc.clone();
+
+    let arc_ref = &arc;
+    let _ = arc_ref.clone();
+
+    // The pointee type is needed as the result is coerced
+    let x = Arc::new(SomeImpl);
+    let _: Arc<dyn SomeTrait> = x.clone();
+}
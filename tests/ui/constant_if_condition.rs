@@ -0,0 +1,80 @@
+#![warn(clippy::constant_if_condition)]
+#![allow(clippy::needless_bool, clippy::nonminimal_bool, clippy::overly_complex_bool_expr)]
+
+const DEBUG: bool = false;
+const LIMIT: u32 = 10;
+
+fn body() {}
+fn alt() {}
+
+fn main() {
+    if true {
+        body();
+    } else {
+        alt();
+    }
+
+    if false {
+        body();
+    } else {
+        alt();
+    }
+
+    if false {
+        body();
+    }
+
+    let _ = if true { 1 } else { 2 };
+
+    let x = 3;
+    if false {
+        body();
+    } else if x > 5 {
+        alt();
+    }
+
+    if x > 2 {
+        body();
+    } else if true {
+        alt();
+    }
+
+    // constant expressions
+    if !false && 2 > 1 {
+        body();
+    }
+    if 1 > 2 {
+        body();
+    } else {
+        alt();
+    }
+
+    // no lint: not constant
+    if x == 3 {
+        body();
+    }
+
+    // no lint: named constants may depend on the configuration
+    if DEBUG {
+        body();
+    }
+    if LIMIT > 5 {
+        body();
+    }
+    if usize::BITS == 64 {
+        body();
+    }
+
+    // no lint: comes from a macro
+    if cfg!(debug_assertions) {
+        body();
+    }
+    macro_rules! always {
+        () => {
+            if true {
+                body();
+            }
+        };
+    }
+    always!();
+}
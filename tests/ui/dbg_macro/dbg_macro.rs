@@ -25,6 +25,10 @@ fn main() {
     //~^ ERROR: the `dbg!` macro is intended as a debugging tool
     dbg!(1, 2, 3, 4, 5);
     //~^ ERROR: the `dbg!` macro is intended as a debugging tool
+    let _ = dbg!(1, 2,);
+    //~^ ERROR: the `dbg!` macro is intended as a debugging tool
+    let _ = dbg!(factorial(4),);
+    //~^ ERROR: the `dbg!` macro is intended as a debugging tool
 }
 
 fn issue9914() {
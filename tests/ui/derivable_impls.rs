@@ -98,6 +98,26 @@ impl Default for StrDefault<'_> {
     }
 }
 
+struct OwnedDefault {
+    name: String,
+    alias: String,
+    count: u8,
+    enabled: bool,
+    parent: Option<Box<OwnedDefault>>,
+}
+
+impl Default for OwnedDefault {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            alias: String::from(""),
+            count: 0,
+            enabled: false,
+            parent: None,
+        }
+    }
+}
+
 #[derive(Default)]
 struct AlreadyDerived(i32, bool);
 
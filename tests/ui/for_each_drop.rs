@@ -0,0 +1,31 @@
+#![warn(clippy::for_each_drop)]
+#![allow(clippy::needless_for_each, clippy::redundant_closure, clippy::unused_unit)]
+#![allow(dropping_references)]
+
+fn main() {
+    let v = vec![String::new(), String::new()];
+
+    v.clone().into_iter().for_each(|x| drop(x));
+    v.clone().into_iter().for_each(|x| {
+        drop(x);
+    });
+    v.clone().into_iter().for_each(|_| {});
+    v.clone().into_iter().for_each(|_| ());
+    v.clone().into_iter().for_each(|x| {
+        let _ = x;
+    });
+    (0..10).for_each(|_| {});
+
+    // no lint: the items are used
+    v.clone().into_iter().for_each(|x| println!("{x}"));
+    v.clone().into_iter().for_each(|x| {
+        let _ = x.len();
+    });
+    // no lint: something else is dropped
+    let s = String::new();
+    v.iter().for_each(move |_| drop(s.clone()));
+    // no lint: dropping references is linted by `dropping_references`
+    v.iter().for_each(|x| drop(x));
+    // no lint: the result is used
+    let () = v.iter().for_each(|_| {});
+}
@@ -25,6 +25,8 @@ fn main() {
     );
 
     let _ = format!("");
+    let _ = format!("foo",);
+    let _ = format!("{{{{ abc }}}}");
 
     format!("{}", "foo");
     format!("{:?}", "foo"); // Don't warn about `Debug`.
@@ -89,4 +91,14 @@ fn main() {
     let _ = format!("{abc}");
     let xx = "xx";
     let _ = format!("{xx}");
+
+    // `clone` for a `String` and `to_owned` for a `str`, but `to_string` behind more references
+    let s = String::from("s");
+    let s_ref = &s;
+    let _ = format!("{s}");
+    let _ = format!("{s_ref}");
+    let str_ref_ref = &x;
+    let _ = format!("{str_ref_ref}");
+    let s_ref_ref = &s_ref;
+    let _ = format!("{s_ref_ref}");
 }
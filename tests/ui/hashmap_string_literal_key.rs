@@ -0,0 +1,60 @@
+#![warn(clippy::hashmap_string_literal_key)]
+
+use std::collections::HashMap;
+
+fn literal_keys() {
+    let mut map: HashMap<String, u32> = HashMap::new();
+    //~^ ERROR: this `HashMap` has `String` keys, but only ever uses string literals as keys
+    map.insert(String::from("one"), 1);
+    map.insert("two".to_string(), 2);
+    map.insert("three".to_owned(), 3);
+    map.insert("four".into(), 4);
+    assert!(map.contains_key("one"));
+    assert_eq!(map.get("two"), Some(&2));
+    map.remove(&String::from("three"));
+}
+
+fn inferred_key_type() {
+    let mut map = HashMap::with_capacity(2);
+    //~^ ERROR: this `HashMap` has `String` keys, but only ever uses string literals as keys
+    map.insert(String::from("one"), 1);
+    if map.contains_key("one") {
+        map.insert(String::from("two"), 2);
+    }
+}
+
+fn runtime_key(name: &str) {
+    let mut map: HashMap<String, u32> = HashMap::new();
+    map.insert(String::from("one"), 1);
+    map.insert(name.to_string(), 2);
+}
+
+fn runtime_lookup(name: &str) -> bool {
+    let mut map: HashMap<String, u32> = HashMap::new();
+    map.insert(String::from("one"), 1);
+    map.contains_key(name)
+}
+
+fn other_use() -> usize {
+    let mut map: HashMap<String, u32> = HashMap::new();
+    map.insert(String::from("one"), 1);
+    map.keys().map(String::len).sum()
+}
+
+fn escapes() -> HashMap<String, u32> {
+    let mut map = HashMap::new();
+    map.insert(String::from("one"), 1);
+    map
+}
+
+fn no_insert() -> bool {
+    let map: HashMap<String, u32> = HashMap::new();
+    map.contains_key("one")
+}
+
+fn str_keys() {
+    let mut map: HashMap<&str, u32> = HashMap::new();
+    map.insert("one", 1);
+}
+
+fn main() {}
@@ -27,4 +27,15 @@ fn main() {
 
     // Issue #6703
     let _: Vec<isize> = v.iter().copied().collect();
+
+    let _ = v.iter().cloned().collect::<Vec<_>>();
+    let _ = v.iter().copied().collect::<Vec<isize>>();
+
+    // Not a slice
+    let set: HashSet<isize> = HashSet::new();
+    let _ = set.iter().cloned().collect::<Vec<_>>();
+}
+
+fn slice_param(names: &[String]) -> Vec<String> {
+    names.iter().cloned().collect::<Vec<_>>()
 }
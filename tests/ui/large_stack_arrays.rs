@@ -1,6 +1,7 @@
 //@aux-build:proc_macros.rs
+//@no-rustfix
 #![warn(clippy::large_stack_arrays)]
-#![allow(clippy::large_enum_variant)]
+#![allow(clippy::large_enum_variant, clippy::large_const_arrays)]
 
 extern crate proc_macros;
 
@@ -20,6 +21,7 @@ pub static DOESNOTLINT2: [u8; 512_001] = {
     let x = 0;
     [x; 512_001]
 };
+pub const DOESNOTLINT3: [u8; 512_001] = [0; 512_001];
 
 fn issue_10741() {
     #[derive(Copy, Clone)]
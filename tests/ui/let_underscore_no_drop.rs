@@ -0,0 +1,70 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::let_underscore_no_drop)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::unnecessary_wraps, unused)]
+
+extern crate proc_macros;
+
+use proc_macros::external;
+
+struct Plain {
+    x: u32,
+}
+
+struct WithDrop;
+
+impl Drop for WithDrop {
+    fn drop(&mut self) {}
+}
+
+fn count() -> u32 {
+    1
+}
+
+fn plain() -> Plain {
+    Plain { x: 1 }
+}
+
+#[must_use]
+fn must_use() -> u32 {
+    1
+}
+
+fn result() -> Result<u32, u32> {
+    Ok(1)
+}
+
+fn ptr() -> *const u32 {
+    std::ptr::null()
+}
+
+fn generic<T: Default>() {
+    let _ = T::default();
+}
+
+fn main() {
+    let mut v = vec![1, 2];
+    let _ = v.pop();
+    let _ = count();
+    let _ = plain();
+    let _ = (count(), 'a');
+    let _ = 1 + 2;
+
+    // No side effects, the whole statement can go
+    let x = 1;
+    let _ = x;
+    let p = Plain { x: 2 };
+    let _ = p.x;
+
+    // Don't lint
+    let _ = WithDrop;
+    let _ = v;
+    let _ = String::new();
+    let _ = Some(WithDrop);
+    let _ = must_use();
+    let _ = result();
+    let _ = ptr();
+    let _: u32 = count();
+    external! {
+        let _ = 1;
+    }
+}
@@ -1,4 +1,4 @@
-#![allow(unused, clippy::map_identity)]
+#![allow(unused, clippy::for_each_drop, clippy::map_identity)]
 #![warn(clippy::lines_filter_map_ok)]
 
 use std::io::{self, BufRead, BufReader};
@@ -0,0 +1,90 @@
+#![warn(clippy::manual_ok)]
+#![allow(clippy::redundant_closure)]
+
+fn make_err() -> String {
+    String::from("missing")
+}
+
+fn f(res: Result<u32, String>, opt: Option<u32>, msg: String) {
+    let _ = match res.clone() {
+        //~^ ERROR: manual implementation of `Result::ok`
+        Ok(v) => Some(v),
+        Err(_) => None,
+    };
+    let _ = match res.clone() {
+        //~^ ERROR: manual implementation of `Result::ok`
+        Err(_) => None,
+        Ok(v) => Some(v),
+    };
+    let _ = match res.clone() {
+        //~^ ERROR: manual implementation of `Result::ok`
+        Ok(v) => Some(v),
+        _ => None,
+    };
+    let _ = match res.clone() {
+        //~^ ERROR: manual implementation of `Result::err`
+        Ok(_) => None,
+        Err(e) => Some(e),
+    };
+
+    let _: Result<u32, &str> = match opt {
+        //~^ ERROR: manual implementation of `Option::ok_or`
+        Some(v) => Ok(v),
+        None => Err("missing"),
+    };
+    let _: Result<u32, String> = match opt {
+        //~^ ERROR: manual implementation of `Option::ok_or_else`
+        Some(v) => Ok(v),
+        None => Err(make_err()),
+    };
+    let _: Result<u32, String> = match opt {
+        //~^ ERROR: manual implementation of `Option::ok_or_else`
+        None => Err(format!("missing {}", 1)),
+        Some(v) => Ok(v),
+    };
+
+    // no lint: the value is changed
+    let _ = match res.clone() {
+        Ok(v) => Some(v + 1),
+        Err(_) => None,
+    };
+    // no lint: guard
+    let _ = match res.clone() {
+        Ok(v) if v > 1 => Some(v),
+        _ => None,
+    };
+    // no lint: by reference
+    let _ = match &res {
+        Ok(v) => Some(v),
+        Err(_) => None,
+    };
+    // no lint: the error is used
+    let _ = match res.clone() {
+        Ok(v) => Some(v),
+        Err(e) => {
+            println!("{e}");
+            None
+        },
+    };
+    // no lint: coerced
+    let _: Option<&[u32]> = match Ok::<_, ()>(&[1u32; 2]) {
+        Ok(v) => Some(v),
+        Err(_) => None,
+    };
+    let _: Result<u32, &str> = match opt {
+        Some(v) => Ok(v),
+        None => Err(&msg),
+    };
+}
+
+#[allow(clippy::diverging_sub_expression)]
+fn early_return(opt: Option<u32>) -> Result<u32, &'static str> {
+    // no lint: the error arm returns
+    let v: Result<u32, &str> = match opt {
+        Some(v) => Ok(v),
+        None => Err(return Err("missing")),
+    };
+    v
+}
+
+fn main() {}
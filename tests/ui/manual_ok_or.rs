@@ -2,6 +2,7 @@
 #![allow(clippy::or_fun_call)]
 #![allow(clippy::disallowed_names)]
 #![allow(clippy::redundant_closure)]
+#![allow(clippy::manual_ok)]
 #![allow(dead_code)]
 #![allow(unused_must_use)]
 
@@ -35,10 +36,12 @@ fn main() {
     // not applicable, expr is not a `Result` value
     foo.map_or(42, |v| v);
 
-    // TODO patterns not covered yet
+    // linted by `manual_ok`
     match foo {
         Some(v) => Ok(v),
         None => Err("error"),
     };
+
+    // TODO patterns not covered yet
     foo.map_or_else(|| Err("error"), |v| Ok(v));
 }
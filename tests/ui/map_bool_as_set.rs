@@ -0,0 +1,130 @@
+#![warn(clippy::map_bool_as_set)]
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn locals(names: &[&str]) {
+    let mut seen = HashMap::new();
+    //~^ ERROR: this map only ever contains `true` values
+    for name in names {
+        if seen.contains_key(name) {
+            continue;
+        }
+        seen.insert(*name, true);
+    }
+    seen.remove("a");
+
+    let mut ordered: BTreeMap<u32, bool> = BTreeMap::new();
+    //~^ ERROR: this map only ever contains `true` values
+    ordered.insert(1, true);
+    assert_eq!(ordered.len(), 1);
+
+    // `false` is inserted
+    let mut flags = HashMap::new();
+    flags.insert("a", true);
+    flags.insert("b", false);
+
+    // The value isn't a literal
+    let mut computed = HashMap::new();
+    computed.insert("a", names.is_empty());
+
+    // The values are read
+    let mut read = HashMap::new();
+    read.insert("a", true);
+    if let Some(value) = read.get("a") {
+        println!("{value}");
+    }
+
+    // The map escapes
+    let mut passed = HashMap::new();
+    passed.insert("a", true);
+    takes_map(&passed);
+
+    // Never inserted into
+    let empty: HashMap<u32, bool> = HashMap::new();
+    assert!(empty.is_empty());
+
+    // Not empty to begin with
+    let mut from_array = HashMap::from([("a", false)]);
+    from_array.insert("b", true);
+}
+
+fn takes_map(_: &HashMap<&str, bool>) {}
+
+// Callers may insert `false` or read the values
+fn returned() -> HashMap<u32, bool> {
+    let mut map = HashMap::new();
+    map.insert(1, true);
+    map
+}
+
+fn use_returned() -> Option<bool> {
+    let mut map = returned();
+    map.insert(2, false);
+    map.get(&2).copied()
+}
+
+pub fn returned_exported() -> HashMap<u32, bool> {
+    let mut map = HashMap::new();
+    map.insert(1, true);
+    map
+}
+
+struct Visited {
+    nodes: HashMap<usize, bool>,
+    //~^ ERROR: this map only ever contains `true` values
+    edges: HashMap<(usize, usize), bool>,
+    from_outside: HashMap<usize, bool>,
+}
+
+impl Visited {
+    fn new(from_outside: HashMap<usize, bool>) -> Self {
+        Self {
+            nodes: HashMap::new(),
+            edges: HashMap::default(),
+            from_outside,
+        }
+    }
+
+    fn visit(&mut self, node: usize, next: usize) {
+        self.nodes.insert(node, true);
+        self.edges.insert((node, next), true);
+        self.from_outside.insert(node, true);
+    }
+
+    fn is_visited(&self, node: usize) -> bool {
+        self.nodes.contains_key(&node)
+    }
+
+    fn edge(&self, node: usize, next: usize) -> bool {
+        self.edges[&(node, next)]
+    }
+}
+
+struct Destructured {
+    by_name: HashMap<u32, bool>,
+}
+
+struct DestructuredTuple(HashMap<u32, bool>);
+
+fn destructure(s: &mut Destructured, t: &mut DestructuredTuple) {
+    s.by_name.insert(1, true);
+    let Destructured { by_name } = s;
+    by_name.insert(2, false);
+
+    t.0.insert(1, true);
+    let DestructuredTuple(map) = t;
+    map.insert(2, false);
+}
+
+pub struct Exported {
+    pub seen: HashMap<usize, bool>,
+}
+
+impl Exported {
+    pub fn see(&mut self, node: usize) {
+        self.seen.insert(node, true);
+    }
+}
+
+fn main() {}
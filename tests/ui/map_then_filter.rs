@@ -0,0 +1,57 @@
+#![warn(clippy::map_then_filter)]
+
+fn expensive(x: u32) -> u32 {
+    x * 2
+}
+
+fn main() {
+    let v = [1, 2, 3];
+    let flag = true;
+
+    let _: Vec<_> = v.iter().map(|x| (x, expensive(*x))).filter(|(x, _)| **x > 1).collect();
+    //~^ ERROR: this `filter` doesn't depend on the values computed by the `map` closure
+    let _: Vec<_> = v
+        .iter()
+        .copied()
+        .map(|x| (expensive(x), x))
+        .filter(|&(_, x)| x % 2 == 0)
+        //~^^ ERROR: this `filter` doesn't depend on the values computed by the `map` closure
+        .collect();
+    let _: Vec<_> = v.iter().map(|x| expensive(*x)).filter(|_| flag).collect();
+    //~^ ERROR: this `filter` doesn't depend on the values computed by the `map` closure
+
+    // The filter uses the computed value
+    let _: Vec<_> = v.iter().map(|x| expensive(*x)).filter(|y| *y > 2).collect();
+    let _: Vec<_> = v.iter().map(|x| (x, expensive(*x))).filter(|(_, y)| *y > 2).collect();
+    let _: Vec<_> = v.iter().map(|x| (x, expensive(*x))).filter(|p| *p.0 > 1).collect();
+    // The element isn't the argument of the `map` closure
+    let _: Vec<_> = v
+        .iter()
+        .map(|x| (x + 1, expensive(*x)))
+        .filter(|(x, _)| *x > 1)
+        .collect();
+    // The `map` closure mutates its captures
+    let mut n = 0;
+    let _: Vec<_> = v
+        .iter()
+        .map(|x| {
+            n += 1;
+            x * 2
+        })
+        .filter(|_| flag)
+        .collect();
+    let mut seen = Vec::new();
+    let _: Vec<_> = v
+        .iter()
+        .map(|x| {
+            seen.push(*x);
+            (x, expensive(*x))
+        })
+        .filter(|(x, _)| **x > 1)
+        .collect();
+    // Not closures
+    let _: Vec<_> = v.iter().copied().map(expensive).filter(|_| flag).collect();
+    // Not an iterator
+    let opt = Some(1);
+    let _ = opt.map(expensive).filter(|_| flag);
+}
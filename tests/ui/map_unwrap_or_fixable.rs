@@ -21,6 +21,15 @@ fn option_methods() {
     // Should not lint.
     let _ = opt_map!(opt, |x| x + 1).unwrap_or_else(|| 0);
 
+    // Functions instead of closures
+    fn double(x: i32) -> i32 { x * 2 }
+    fn zero() -> i32 { 0 }
+    let _ = opt.map(double).unwrap_or_else(zero);
+
+    // Check for `option.map(_).unwrap_or(_)` use with a constant default.
+    let _ = opt.map(double).unwrap_or(0);
+    let _ = opt.map(|x| x > 1).unwrap_or(true);
+
     // Issue #4144
     {
         let mut frequencies = HashMap::new();
@@ -86,7 +86,7 @@ fn main() {
         };
     }
     {
-        // no lint
+        // lint
         let _ans = match x {
             E::A(_) => false,
             E::B(_) => false,
@@ -95,7 +95,7 @@ fn main() {
         };
     }
     {
-        // no lint
+        // lint
         let _ans = match x {
             E::A(_) => true,
             E::B(_) => false,
@@ -128,7 +128,7 @@ fn main() {
         };
     }
     {
-        // no lint
+        // lint
         let _ans = match x {
             E::A(_) => false,
             E::B(_) => true,
@@ -254,3 +254,52 @@ fn msrv_1_42() {
         _ => false,
     };
 }
+
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+    Up(u8),
+    Down { depth: u8 },
+}
+
+fn mixed_arms(d: Direction) {
+    let _ = match d {
+        Direction::North => true,
+        Direction::East => false,
+        Direction::South => true,
+        Direction::West => false,
+        Direction::Up(_) => false,
+        Direction::Down { .. } => false,
+    };
+
+    // More `true` arms than `false` ones
+    let _ = match d {
+        Direction::North | Direction::South => true,
+        Direction::East => true,
+        Direction::West => false,
+        Direction::Up(..) => true,
+        Direction::Down { depth: _ } => false,
+    };
+
+    let _ = match d {
+        Direction::North => true,
+        Direction::East => false,
+        Direction::South => true,
+        _ => false,
+    };
+
+    let _ = match d {
+        Direction::North => false,
+        Direction::East => true,
+        _ => true,
+    };
+
+    // The arms can't be reordered
+    let _ = match d {
+        Direction::Up(0) => false,
+        Direction::Up(_) => true,
+        _ => false,
+    };
+}
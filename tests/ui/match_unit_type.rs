@@ -0,0 +1,40 @@
+#![feature(never_type)]
+#![warn(clippy::match_unit_type)]
+#![allow(
+    clippy::diverging_sub_expression,
+    clippy::match_single_binding,
+    clippy::no_effect,
+    unreachable_code
+)]
+
+fn f() -> u32 {
+    1
+}
+
+fn never(x: !) -> u32 {
+    match x {}
+    //~^ ERROR: this match on `!` could be replaced by its scrutinee
+}
+
+fn exit() -> u32 {
+    match std::process::exit(1) {}
+    //~^ ERROR: this match on `!` could be replaced by its scrutinee
+}
+
+fn main() {
+    // Linted by `match_single_binding`
+    let _ = match () {
+        () => f(),
+    };
+    match () {
+        _ => println!("unit"),
+    }
+
+    // Don't lint
+    macro_rules! never_match {
+        ($e:expr) => {
+            match $e {}
+        };
+    }
+    let _: u32 = never_match!(std::process::exit(1));
+}
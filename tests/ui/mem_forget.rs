@@ -4,6 +4,12 @@ use std::sync::Arc;
 use std::mem as memstuff;
 use std::mem::forget as forgetSomething;
 
+struct Handle;
+
+impl Drop for Handle {
+    fn drop(&mut self) {}
+}
+
 #[warn(clippy::mem_forget)]
 #[allow(forgetting_copy_types)]
 fn main() {
@@ -30,5 +36,10 @@ fn main() {
     //~^ ERROR: usage of `mem::forget` on type with `Drop` fields
     //~| NOTE: argument has type `std::string::String`
 
+    let handle = Handle;
+    std::mem::forget(handle);
+    //~^ ERROR: usage of `mem::forget` on `Drop` type
+    //~| NOTE: argument has type `Handle`
+
     std::mem::forget(7);
 }
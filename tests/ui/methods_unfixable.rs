@@ -1,4 +1,4 @@
-#![warn(clippy::filter_next)]
+#![warn(clippy::filter_next, clippy::filter_map_next)]
 //@no-rustfix
 fn main() {
     issue10029();
@@ -8,4 +8,7 @@ pub fn issue10029() {
     let iter = (0..10);
     let _ = iter.filter(|_| true).next();
     //~^ ERROR: called `filter(..).next()` on an `Iterator`. This is more succinctly expre
+    let iter = (0..10u32);
+    let _ = iter.filter_map(|x| x.checked_sub(1)).next();
+    //~^ ERROR: called `filter_map(..).next()` on an `Iterator`. This is more succinctly expre
 }
@@ -0,0 +1,82 @@
+#![warn(clippy::missing_must_use_on_option)]
+#![allow(clippy::must_use_candidate, clippy::unnecessary_wraps)]
+
+pub fn first_even(values: &[u32]) -> Option<u32> {
+    //~^ ERROR: this function returns an `Option`, but has no `#[must_use]` attribute
+    values.iter().copied().find(|v| v % 2 == 0)
+}
+
+/// Documented.
+pub fn documented() -> std::option::Option<u32> {
+    //~^ ERROR: this function returns an `Option`, but has no `#[must_use]` attribute
+    Some(1)
+}
+
+pub mod nested {
+    pub fn nested() -> Option<u32> {
+        //~^ ERROR: this function returns an `Option`, but has no `#[must_use]` attribute
+        None
+    }
+}
+
+// no lint: already `#[must_use]`
+#[must_use]
+pub fn annotated() -> Option<u32> {
+    None
+}
+
+// no lint: `try_` functions
+pub fn try_parse(s: &str) -> Option<u32> {
+    s.parse().ok()
+}
+
+// no lint: `Result` is already `#[must_use]`
+pub fn fallible() -> Result<u32, String> {
+    Ok(1)
+}
+
+// no lint: type aliases are not resolved
+pub type Maybe<T> = Option<T>;
+pub fn aliased() -> Maybe<u32> {
+    None
+}
+
+// no lint: not public
+fn private() -> Option<u32> {
+    None
+}
+
+// no lint: methods and trait impls
+pub struct S;
+
+impl S {
+    pub fn method(&self) -> Option<u32> {
+        None
+    }
+}
+
+impl Iterator for S {
+    type Item = u32;
+    fn next(&mut self) -> Option<u32> {
+        None
+    }
+}
+
+// no lint: reported by `must_use_candidate`
+#[warn(clippy::must_use_candidate)]
+pub fn candidate(x: u32) -> Option<u32> {
+    //~^ ERROR: this function could have a `#[must_use]` attribute
+    x.checked_add(1)
+}
+
+// `must_use_candidate` skips functions with mutable arguments
+#[warn(clippy::must_use_candidate)]
+pub fn not_a_candidate(x: &mut u32) -> Option<u32> {
+    //~^ ERROR: this function returns an `Option`, but has no `#[must_use]` attribute
+    *x += 1;
+    Some(*x)
+}
+
+fn main() {
+    let _ = private();
+}
@@ -0,0 +1,28 @@
+#![allow(clippy::let_unit_value, clippy::unused_unit, unused_braces)]
+#![warn(clippy::never_loop)]
+
+fn compute() -> u32 {
+    42
+}
+
+fn main() {
+    let _ = loop {
+        break compute();
+    };
+
+    let _ = loop {
+        break compute() + 1;
+    };
+
+    let _: () = loop {
+        break ();
+    };
+
+    loop {
+        break;
+    }
+
+    let _ = loop {
+        break;
+    };
+}
@@ -0,0 +1,63 @@
+//@aux-build:lazy_static.rs
+#![warn(clippy::prefer_once_lock)]
+#![allow(dead_code)]
+
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+fn load_names() -> Vec<String> {
+    vec![String::from("a")]
+}
+
+lazy_static! {
+    static ref NAMES: Vec<String> = load_names();
+}
+
+lazy_static! {
+    /// The default settings.
+    pub static ref SETTINGS: HashMap<&'static str, u32> = {
+        let mut map = HashMap::new();
+        map.insert("retries", 3);
+        map
+    };
+    pub(crate) static ref GREETING: String = String::from("hello");
+}
+
+mod inner {
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        pub(crate) static ref COUNT: usize = super::load_names().len();
+    }
+}
+
+// Not the `lazy_static` crate
+macro_rules! lazy_static_like {
+    (static ref $N:ident : $T:ty = $e:expr;) => {
+        static $N: $T = $e;
+    };
+}
+
+lazy_static_like! {
+    static ref LIMIT: u32 = 10;
+}
+
+#[clippy::msrv = "1.69"]
+mod msrv_too_low {
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref OLD: u32 = 1;
+    }
+}
+
+#[clippy::msrv = "1.70"]
+mod msrv_meets {
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref NEW: u32 = 1;
+    }
+}
+
+fn main() {}
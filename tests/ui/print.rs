@@ -21,6 +21,18 @@ impl Debug for Foo {
     }
 }
 
+#[cfg(debug_assertions)]
+fn debug_only() {
+    // no lint: only compiled with debug assertions
+    let _ = format!("{:?}", 3);
+}
+
+#[test]
+fn test() {
+    // no lint: in a test
+    let _ = format!("{:?}", 4);
+}
+
 fn main() {
     println!("Hello");
     //~^ ERROR: use of `println!`
@@ -39,6 +51,16 @@ fn main() {
     //~^ ERROR: use of `print!`
     //~| ERROR: use of `Debug`-based formatting
 
+    let _ = format!("{:?}", 1);
+    //~^ ERROR: use of `Debug`-based formatting
+
+    // no lint: only compiled with debug assertions
+    #[cfg(debug_assertions)]
+    {
+        println!("{:?}", 2);
+        //~^ ERROR: use of `println!`
+    }
+
     assert_eq!(42, 1337);
 
     vec![1, 2];
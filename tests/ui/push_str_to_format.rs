@@ -0,0 +1,82 @@
+#![warn(clippy::push_str_to_format)]
+#![allow(clippy::single_char_add_str)]
+
+fn literals() {
+    let mut s = String::new();
+    //~^ ERROR: calls to `push_str` immediately after creating an empty `String`
+    s.push_str("Hello");
+    s.push_str(", ");
+    s.push_str("{world}!\n");
+    println!("{s}");
+}
+
+fn variables(name: &str, greeting: String) {
+    let mut s = String::new();
+    //~^ ERROR: calls to `push_str` immediately after creating an empty `String`
+    s.push_str(&greeting);
+    s.push_str(", ");
+    s.push_str(name);
+    println!("{s}");
+}
+
+fn expressions(names: &[String]) {
+    let mut s: String = String::new();
+    //~^ ERROR: calls to `push_str` immediately after creating an empty `String`
+    s.push_str(&names[0]);
+    s.push_str(" and ");
+    s.push_str(names[1].trim());
+    println!("{s}");
+}
+
+fn mutated_afterwards(name: &str) {
+    let mut s = String::new();
+    //~^ ERROR: calls to `push_str` immediately after creating an empty `String`
+    s.push_str("Hello, ");
+    s.push_str(name);
+    s.push_str("!");
+    if name.is_empty() {
+        s.push('?');
+    }
+    println!("{s}");
+}
+
+fn not_enough_pushes(name: &str) {
+    let mut s = String::new();
+    s.push_str("Hello, ");
+    s.push_str(name);
+    println!("{s}");
+}
+
+fn interrupted(name: &str) {
+    let mut s = String::new();
+    s.push_str("Hello, ");
+    println!("{s}");
+    s.push_str(name);
+    s.push_str("!");
+}
+
+fn branching(name: &str) {
+    let mut s = String::new();
+    s.push_str("Hello, ");
+    if !name.is_empty() {
+        s.push_str(name);
+    }
+    s.push_str("!");
+}
+
+fn not_empty(name: &str) {
+    let mut s = String::from("Hello");
+    s.push_str(", ");
+    s.push_str(name);
+    s.push_str("!");
+}
+
+fn raw_string(name: &str) {
+    let mut s = String::new();
+    //~^ ERROR: calls to `push_str` immediately after creating an empty `String`
+    s.push_str(r#"say "hi" to "#);
+    s.push_str(name);
+    s.push_str("!");
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+#![warn(clippy::rc_refcell_undocumented)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+
+pub struct Undocumented {
+    pub shared: Rc<RefCell<Vec<u8>>>,
+    //~^ ERROR: `Rc<RefCell<_>>` field without a doc comment
+    locked: Arc<Mutex<u32>>,
+    //~^ ERROR: `Arc<Mutex<_>>` field without a doc comment
+}
+
+pub struct Documented {
+    /// Only borrowed mutably while no other borrow is live.
+    pub shared: Rc<RefCell<Vec<u8>>>,
+    #[doc = "Never locked while holding another lock."]
+    locked: Arc<Mutex<u32>>,
+}
+
+pub struct Other {
+    plain: Rc<u8>,
+    cell: RefCell<u8>,
+    rw: Arc<RwLock<u8>>,
+    // A regular comment doesn't count as documentation
+    commented: Rc<RefCell<u8>>,
+    //~^ ERROR: `Rc<RefCell<_>>` field without a doc comment
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//@no-rustfix
+#![warn(clippy::rc_refcell_undocumented)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+pub struct Tuple(Rc<RefCell<u8>>);
+//~^ ERROR: `Rc<RefCell<_>>` field without a doc comment
+
+pub struct DocumentedTuple(
+    /// Only borrowed mutably while no other borrow is live.
+    Rc<RefCell<u8>>,
+    Arc<Mutex<u8>>,
+    //~^ ERROR: `Arc<Mutex<_>>` field without a doc comment
+);
+
+fn main() {}
@@ -0,0 +1,72 @@
+#![warn(clippy::redundant_allow_unused_variables)]
+#![allow(dead_code, unused_assignments)]
+
+#[allow(unused_variables)]
+fn prefixed(_a: u32) {
+    let _b = 1;
+}
+
+#[allow(unused_variables)]
+fn all_used(a: u32) -> u32 {
+    let b = a + 1;
+    let c = |d: u32| d + b;
+    c(2)
+}
+
+struct S;
+
+#[allow(unused_variables)]
+impl S {
+    fn f(&self, _a: u32) {}
+
+    fn g(&self, a: u32) -> u32 {
+        a
+    }
+}
+
+impl S {
+    #[allow(unused_variables)]
+    fn h(&self, _a: u32) {}
+}
+
+// Don't lint
+
+#[allow(unused_variables)]
+fn unused(a: u32) {}
+
+#[allow(unused_variables)]
+fn unused_local() {
+    let (a, _b) = (1, 2);
+}
+
+#[allow(unused_variables)]
+fn only_assigned() {
+    let mut a = 1;
+    a = 2;
+}
+
+#[allow(unused_variables)]
+fn nested_item() {
+    fn inner(a: u32) {}
+}
+
+#[allow(unused_variables)]
+impl S {
+    fn i(&self, a: u32) {}
+}
+
+macro_rules! binding {
+    () => {
+        let a = 1;
+    };
+}
+
+#[allow(unused_variables)]
+fn from_macro() {
+    binding!();
+}
+
+#[allow(unused_variables, unused_mut)]
+fn other_lints(_a: u32) {}
+
+fn main() {}
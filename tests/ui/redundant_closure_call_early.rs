@@ -1,3 +1,4 @@
+//@no-rustfix: `move` closures and closures capturing variables get no suggestion
 // non rustfixable, see redundant_closure_call_fixable.rs
 
 #![warn(clippy::redundant_closure_call)]
@@ -14,6 +15,12 @@ fn main() {
     k = (|a, b| a * b)(1, 5);
     //~^ ERROR: try not to call a closure in the expression where it is declared
 
+    // lint here, without a suggestion
+    k = (move |m| m + 1)(i);
+    //~^ ERROR: try not to call a closure in the expression where it is declared
+    k = (|m| m + i)(2);
+    //~^ ERROR: try not to call a closure in the expression where it is declared
+
     // don't lint these
     #[allow(clippy::needless_return)]
     (|| return 2)();
@@ -0,0 +1,34 @@
+#![warn(clippy::redundant_closure_call)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation)]
+
+fn main() {
+    let i = 1;
+
+    let _ = (|x| x + 1)(i);
+    //~^ ERROR: try not to call a closure in the expression where it is declared
+    let _ = (|x: u32, y: u32| x * y)(2, 3);
+    //~^ ERROR: try not to call a closure in the expression where it is declared
+    let _ = (|(a, b): (u32, u32)| {
+        //~^ ERROR: try not to call a closure in the expression where it is declared
+        let sum = a + b;
+        sum * 2
+    })((1, 2));
+
+    let (x, y) = (5, 3);
+    let _ = (|x: i32, y: i32| x - y)(y, x);
+    //~^ ERROR: try not to call a closure in the expression where it is declared
+    let _ = (|x, y: i32| x - y)(y, x);
+    //~^ ERROR: try not to call a closure in the expression where it is declared
+}
+
+fn early_exit(x: Option<u32>) -> Option<u32> {
+    // Inlining the body would return from `early_exit`
+    let _ = (|x: Option<u32>| Some(x? + 1))(x);
+    let _ = (|x: u32| {
+        if x > 1 {
+            return 0;
+        }
+        x
+    })(2);
+    x
+}
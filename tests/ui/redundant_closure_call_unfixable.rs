@@ -0,0 +1,15 @@
+//@no-rustfix
+#![warn(clippy::redundant_closure_call)]
+#![allow(clippy::never_loop)]
+
+fn main() {
+    // No suggestion is given for bodies containing labeled `break`s
+    let _ = (|n: u32| {
+        //~^ ERROR: try not to call a closure in the expression where it is declared
+        'outer: loop {
+            loop {
+                break 'outer n;
+            }
+        }
+    })(1);
+}
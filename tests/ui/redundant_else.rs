@@ -72,6 +72,34 @@ fn main() {
         1
     };
 
+    // panicking macro
+    if foo() {
+        panic!("A penny saved is a penny earned.");
+    } else {
+        //~^ ERROR: redundant else block
+        println!("Well done is better than well said.");
+    }
+    if foo() {
+        unreachable!();
+    } else {
+        //~^ ERROR: redundant else block
+        println!("Lost time is never found again.");
+    }
+    // binding in the else block
+    if foo() {
+        return;
+    } else {
+        //~^ ERROR: redundant else block
+        let x: u32 = foo();
+        println!("{x}");
+    }
+    // empty else block
+    if foo() {
+        return;
+    } else {
+        //~^ ERROR: redundant else block
+    }
+
     //
     // non-lint cases
     //
@@ -148,6 +176,13 @@ fn main() {
     } else {
         2
     };
+    // panic inside of a closure
+    let x: Option<u32> = foo();
+    if foo() {
+        let _ = x.unwrap_or_else(|| panic!());
+    } else {
+        println!("Early to bed and early to rise.");
+    }
     // inside function call
     Box::new(if foo() {
         return;
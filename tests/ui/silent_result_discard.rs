@@ -0,0 +1,72 @@
+#![warn(clippy::silent_result_discard)]
+#![allow(clippy::redundant_pattern_matching)]
+
+fn fallible() -> Result<u32, String> {
+    Ok(1)
+}
+
+fn maybe() -> Option<u32> {
+    Some(1)
+}
+
+fn statements() {
+    if let Ok(value) = fallible() {
+        //~^ ERROR: this `if let` silently ignores the `Err` case
+        println!("{value}");
+    }
+
+    if let Some(value) = maybe() {
+        //~^ ERROR: this `if let` silently ignores the `None` case
+        println!("{value}");
+    }
+
+    // The final expression of the block
+    if let Ok(_) = fallible() {
+        //~^ ERROR: this `if let` silently ignores the `Err` case
+        println!("ok");
+    }
+}
+
+fn handled() {
+    if let Ok(value) = fallible() {
+        println!("{value}");
+    } else {
+        println!("failed");
+    }
+
+    // Not `Ok` or `Some`
+    if let Err(e) = fallible() {
+        println!("{e}");
+    }
+
+    // Used as a value
+    let _value = if let Some(value) = maybe() { value + 1 } else { 0 };
+}
+
+fn loops_and_closures(items: &[&str]) {
+    for item in items {
+        if let Ok(value) = item.parse::<u32>() {
+            println!("{value}");
+        }
+    }
+
+    items.iter().for_each(|item| {
+        if let Ok(value) = item.parse::<u32>() {
+            println!("{value}");
+        }
+    });
+}
+
+macro_rules! ignore_failure {
+    ($e:expr) => {
+        if let Ok(value) = $e {
+            println!("{value}");
+        }
+    };
+}
+
+fn in_macro() {
+    ignore_failure!(fallible());
+}
+
+fn main() {}
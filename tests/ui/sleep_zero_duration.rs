@@ -0,0 +1,60 @@
+#![warn(clippy::sleep_zero_duration)]
+#![allow(clippy::eq_op, clippy::erasing_op, clippy::unused_unit)]
+
+use std::thread::{self, sleep};
+use std::time::Duration;
+
+const DELAY: u64 = 0;
+
+fn work() {}
+
+fn main() {
+    thread::sleep(Duration::ZERO);
+    //~^ ERROR: sleeping for a zero duration has no effect
+    thread::sleep(Duration::from_secs(0));
+    //~^ ERROR: sleeping for a zero duration has no effect
+    std::thread::sleep(Duration::from_millis(0));
+    //~^ ERROR: sleeping for a zero duration has no effect
+    sleep(Duration::from_micros(0));
+    //~^ ERROR: sleeping for a zero duration has no effect
+    sleep(Duration::from_nanos(0));
+    //~^ ERROR: sleeping for a zero duration has no effect
+    sleep(Duration::new(0, 0));
+    //~^ ERROR: sleeping for a zero duration has no effect
+    sleep(Duration::from_secs_f64(0.0));
+    //~^ ERROR: sleeping for a zero duration has no effect
+
+    // constant folding
+    sleep(Duration::from_millis(5 - 5));
+    //~^ ERROR: sleeping for a zero duration has no effect
+    sleep(Duration::new(2 * 0, 10 % 2));
+    //~^ ERROR: sleeping for a zero duration has no effect
+
+    // in expression position
+    let _: () = sleep(Duration::ZERO);
+    //~^ ERROR: sleeping for a zero duration has no effect
+    let millis = 0;
+    if millis > 0 {
+        work()
+    } else {
+        sleep(Duration::from_secs(0))
+        //~^ ERROR: sleeping for a zero duration has no effect
+    }
+
+    // no lint: not zero
+    sleep(Duration::from_millis(1));
+    sleep(Duration::new(0, 1));
+    sleep(Duration::MAX);
+    sleep(Duration::from_millis(millis));
+
+    // no lint: named constants may depend on the configuration
+    sleep(Duration::from_millis(DELAY));
+
+    // no lint: comes from a macro
+    macro_rules! pause {
+        () => {
+            sleep(Duration::ZERO)
+        };
+    }
+    pause!();
+}
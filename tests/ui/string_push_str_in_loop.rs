@@ -0,0 +1,99 @@
+//@no-rustfix: the suggestion has a placeholder for the capacity
+#![warn(clippy::string_push_str_in_loop)]
+
+fn for_loop(names: &[&str]) -> String {
+    let mut s = String::new();
+    //~^ ERROR: this `String` is grown inside a loop without allocating its capacity upfront
+    for name in names {
+        s.push_str(name);
+    }
+    s
+}
+
+fn while_loop(mut n: u32) -> String {
+    let mut s = String::new();
+    //~^ ERROR: this `String` is grown inside a loop without allocating its capacity upfront
+    while n > 0 {
+        s.push('*');
+        n -= 1;
+    }
+    s
+}
+
+fn plain_loop(mut n: u32) -> String {
+    let mut s: String = String::new();
+    //~^ ERROR: this `String` is grown inside a loop without allocating its capacity upfront
+    loop {
+        if n == 0 {
+            break s;
+        }
+        s.push_str("ab");
+        n -= 1;
+    }
+}
+
+fn nested(rows: &[Vec<&str>]) -> String {
+    let mut s = String::new();
+    //~^ ERROR: this `String` is grown inside a loop without allocating its capacity upfront
+    for row in rows {
+        for cell in row {
+            s.push_str(cell);
+        }
+        s.push('\n');
+    }
+    s
+}
+
+fn with_capacity(names: &[&str]) -> String {
+    let mut s = String::with_capacity(names.len() * 4);
+    for name in names {
+        s.push_str(name);
+    }
+    s
+}
+
+fn reserved(names: &[&str]) -> String {
+    let mut s = String::new();
+    s.reserve(names.len() * 4);
+    for name in names {
+        s.push_str(name);
+    }
+    s
+}
+
+fn outside_loop(name: &str) -> String {
+    let mut s = String::new();
+    s.push_str(name);
+    s
+}
+
+fn other_string(names: &[&str]) -> String {
+    let mut s = String::new();
+    let mut t = String::from("x");
+    for name in names {
+        t.push_str(name);
+    }
+    s.push_str(&t);
+    s
+}
+
+fn conditional(names: &[&str]) -> String {
+    let mut s = String::new();
+    for name in names {
+        if !name.is_empty() {
+            s.push_str(name);
+        }
+    }
+    s
+}
+
+fn allowed(names: &[&str]) -> String {
+    #[allow(clippy::string_push_str_in_loop)]
+    let mut s = String::new();
+    for name in names {
+        s.push_str(name);
+    }
+    s
+}
+
+fn main() {}
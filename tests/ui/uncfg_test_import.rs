@@ -0,0 +1,60 @@
+//@aux-build:proc_macros.rs
+#![warn(clippy::uncfg_test_import)]
+#![allow(dead_code)]
+
+extern crate proc_macros;
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Write;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::vec::Vec as List;
+//~^ ERROR: `List` is only used in tests
+
+pub use std::cell::Cell;
+
+mod inner {
+    pub fn helper() {}
+}
+use inner::helper;
+//~^ ERROR: `helper` is only used in tests
+
+pub fn production() -> VecDeque<u8> {
+    let _: BTreeMap<u8, u8> = BTreeMap::new();
+    VecDeque::new()
+}
+
+mod uses_parent_import {
+    use super::BTreeMap;
+
+    fn f() -> BTreeMap<u8, u8> {
+        BTreeMap::new()
+    }
+}
+
+proc_macros::with_span! {
+    span
+    fn from_proc_macro() -> Rc<u8> { Rc::new(0) }
+}
+
+// Test crates don't gate their tests behind `#[cfg(test)]`
+#[test]
+fn test_fn() {
+    use std::sync::Mutex;
+
+    let _ = Arc::new(Mutex::new(0));
+    let _: Cell<u8> = Cell::new(0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let _: List<u8> = List::new();
+        let mut s = String::new();
+        write!(s, "").unwrap();
+        helper();
+    }
+}
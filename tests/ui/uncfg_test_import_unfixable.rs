@@ -0,0 +1,28 @@
+//@no-rustfix
+#![warn(clippy::uncfg_test_import)]
+
+use std::collections::{BTreeMap, HashMap};
+//~^ ERROR: `HashMap` is only used in tests
+use std::collections::{HashSet, VecDeque};
+//~^ ERROR: `HashSet` is only used in tests
+
+pub fn production() -> VecDeque<u8> {
+    let _: BTreeMap<u8, u8> = BTreeMap::new();
+    VecDeque::new()
+}
+
+#[cfg(test)]
+fn test_helper() -> HashMap<u8, u8> {
+    HashMap::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let _: HashSet<u8> = HashSet::new();
+        let _ = test_helper();
+    }
+}
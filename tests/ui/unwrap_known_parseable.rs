@@ -0,0 +1,55 @@
+#![warn(clippy::unwrap_known_parseable)]
+#![allow(clippy::unnecessary_literal_unwrap)]
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4};
+
+fn main() {
+    let _ = "123".parse::<u32>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _: u16 = "8080".parse().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "+5".parse::<i8>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "-128".parse::<i8>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "-3".parse::<i64>().unwrap().abs();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "1.5".parse::<f64>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "2".parse::<f32>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "true".parse::<bool>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+
+    let _ = "192.168.0.1".parse::<Ipv4Addr>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "127.0.0.1".parse::<Ipv4Addr>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "fe80::1".parse::<Ipv6Addr>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "::1".parse::<IpAddr>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "10.0.0.1:80".parse::<SocketAddrV4>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "0.0.0.0:8080".parse::<SocketAddr>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+    let _ = "[::1]:443".parse::<SocketAddr>().unwrap();
+    //~^ ERROR: unwrapping the result of parsing a string literal which is known to be valid
+
+    // Invalid
+    let _ = "256".parse::<u8>().unwrap();
+    let _ = "-1".parse::<u32>().unwrap();
+    let _ = "1_000".parse::<u32>().unwrap();
+    let _ = " 1".parse::<u32>().unwrap();
+    let _ = "256.0.0.1".parse::<Ipv4Addr>().unwrap();
+    let _ = "yes".parse::<bool>().unwrap();
+    // Not written the same way as a literal
+    let _ = "1e3".parse::<f64>().unwrap();
+    let _ = ".5".parse::<f64>().unwrap();
+    let _ = "inf".parse::<f64>().unwrap();
+    // Not a literal
+    let s = "123";
+    let _ = s.parse::<u32>().unwrap();
+    let _ = "123".parse::<u32>().expect("valid");
+    let _ = "123".parse::<String>().unwrap();
+}
@@ -160,4 +160,28 @@ fn missing_suggested_method() {
     s.unwrap_or(Default::default());
 }
 
+#[allow(clippy::redundant_closure)]
+fn closures(option: Option<Vec<u64>>) {
+    use std::collections::HashMap;
+
+    let mut map = HashMap::<u64, Vec<u64>>::new();
+    map.entry(0).or_insert_with(|| Default::default());
+    //~^ ERROR: use of `or_insert_with` to construct default value
+    map.entry(0).or_insert_with(|| Vec::new());
+    //~^ ERROR: use of `or_insert_with` to construct default value
+    map.entry(0).or_insert_with(|| vec![]);
+    //~^ ERROR: use of `or_insert_with` to construct default value
+
+    let mut nested = HashMap::<u64, HashMap<u64, u64>>::new();
+    nested.entry(0).or_insert_with(|| HashMap::new());
+    //~^ ERROR: use of `or_insert_with` to construct default value
+
+    let _ = option.clone().unwrap_or_else(|| Vec::new());
+    //~^ ERROR: use of `unwrap_or_else` to construct default value
+
+    // Not the default value
+    map.entry(0).or_insert_with(|| vec![1]);
+    map.entry(0).or_insert_with(|| Vec::with_capacity(1));
+}
+
 fn main() {}
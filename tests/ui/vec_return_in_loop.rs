@@ -0,0 +1,80 @@
+#![warn(clippy::vec_return_in_loop)]
+#![allow(clippy::while_let_on_iterator)]
+
+fn neighbours(i: u32) -> Vec<u32> {
+    vec![i, i + 1]
+}
+
+struct Graph;
+
+impl Graph {
+    fn edges(&self, i: u32) -> Vec<(u32, u32)> {
+        vec![(i, i + 1)]
+    }
+}
+
+fn main() {
+    let graph = Graph;
+    let mut all = Vec::new();
+
+    for i in 0..10 {
+        let n = neighbours(i);
+        //~^ ERROR: a `Vec` is allocated on every iteration
+        for x in n {
+            all.push(x);
+        }
+    }
+
+    let mut i = 0;
+    while i < 10 {
+        let n = neighbours(i);
+        //~^ ERROR: a `Vec` is allocated on every iteration
+        all.extend(n);
+        i += 1;
+    }
+
+    loop {
+        let edges = graph.edges(1);
+        //~^ ERROR: a `Vec` is allocated on every iteration
+        for (a, _) in edges.iter() {
+            all.push(*a);
+        }
+        if all.len() > 20 {
+            break;
+        }
+    }
+
+    let mut it = 0..3;
+    while let Some(i) = it.next() {
+        let n = neighbours(i);
+        //~^ ERROR: a `Vec` is allocated on every iteration
+        for x in &n {
+            all.push(*x);
+        }
+    }
+
+    // Don't lint
+
+    // Used more than once
+    for i in 0..10 {
+        let n = neighbours(i);
+        all.extend(n.iter());
+        println!("{}", n.len());
+    }
+
+    // Not iterated
+    for i in 0..10 {
+        let n = neighbours(i);
+        println!("{}", n.len());
+    }
+
+    // Not a local function
+    for _ in 0..10 {
+        let v = Vec::from([1, 2]);
+        all.extend(v);
+    }
+
+    // Outside of a loop
+    let n = neighbours(1);
+    all.extend(n);
+}
@@ -456,6 +456,35 @@ fn fn_once_closure() {
     });
 }
 
+fn mut_ref_param(it: &mut impl Iterator<Item = u32>) -> u32 {
+    let mut sum = 0;
+    // Needs `.by_ref()` as `it` is a mutable reference, the body is kept as is
+    while let Some(x) = it.next() {
+        if x == 0 {
+            continue;
+        }
+        sum += x;
+    }
+    sum
+}
+
+fn by_value_iterator(v: Vec<(u32, u32)>) -> Option<(u32, u32)> {
+    // Consumed by the loop, no borrow needed
+    let mut it = v.clone().into_iter();
+    while let Some((a, b)) = it.next() {
+        println!("{a} {b}");
+    }
+
+    // Used after the loop, needs `.by_ref()`
+    let mut it = v.into_iter();
+    while let Some((a, _)) = it.next() {
+        if a == 0 {
+            break;
+        }
+    }
+    it.next()
+}
+
 fn main() {
     let mut it = 0..20;
     while let Some(..) = it.next() {
@@ -1,8 +1,12 @@
 #![warn(clippy::fields_mutated_by_whitelist)]
 
 pub struct TestStruct {
-    #[mutatedby("allowed_function")]
+    #[clippy::mutatedby("allowed_function", "allowed_free_function")]
     field: u8,
+    other: u8,
+    pub unannotated: u8,
+    #[clippy::mutatedby("*")]
+    pub free: u8,
 }
 
 impl TestStruct {
@@ -14,11 +18,49 @@ impl TestStruct {
         self.field += 2; // Should trigger a lint warning
         panic!("Hi!");
     }
+
+    fn borrows_mutably(&mut self) -> &mut u8 {
+        &mut self.field // Should trigger a lint warning
+    }
+
+    fn mutates_in_closure(&mut self) {
+        let mut f = || self.field = 3; // Should trigger a lint warning
+        f();
+    }
+
+    fn reads_only(&mut self) -> u8 {
+        self.other = 4;
+        self.unannotated = 4;
+        self.free = 4;
+        self.field + self.other
+    }
+}
+
+fn free_function(ts: &mut TestStruct) {
+    ts.field = 5; // Should trigger a lint warning
+}
+
+fn allowed_free_function(ts: &mut TestStruct) {
+    ts.field = 6;
+}
+
+fn mutates_local() {
+    let mut ts = TestStruct {
+        field: 0,
+        other: 0,
+        unannotated: 0,
+        free: 0,
+    };
+    ts.field = 7; // Should trigger a lint warning
 }
 
 fn main() {
-    let mut ts = TestStruct { field: 0 };
+    let mut ts = TestStruct {
+        field: 0,
+        other: 0,
+        unannotated: 0,
+        free: 0,
+    };
     ts.allowed_function();
-    ts.disallowed_function(); // This should trigger a lint warning
+    ts.disallowed_function();
 }
-
@@ -0,0 +1,52 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::ops::AddAssign;
+
+#[derive(Default)]
+pub struct Total(u32);
+
+impl AddAssign<u32> for Total {
+    fn add_assign(&mut self, rhs: u32) {
+        self.0 += rhs;
+    }
+}
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    name: String,
+    #[clippy::mutatedby("allowed_function")]
+    total: Total,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        self.name += "suffix";
+        self.total += 1;
+    }
+
+    fn disallowed_string(&mut self) {
+        self.name += "suffix";
+        //~^ ERROR: field `name` is mutated by `disallowed_string`
+    }
+
+    fn disallowed_string_ref(&mut self, suffix: &String) {
+        self.name += suffix;
+        //~^ ERROR: field `name` is mutated by `disallowed_string_ref`
+    }
+
+    fn disallowed_custom(&mut self) {
+        self.total += 2;
+        //~^ ERROR: field `total` is mutated by `disallowed_custom`
+    }
+
+    fn disallowed_explicit_call(&mut self) {
+        self.total.add_assign(3);
+        //~^ ERROR: field `total` is mutated by `disallowed_explicit_call`
+    }
+
+    fn read_only(&self) -> String {
+        self.name.clone() + "suffix"
+    }
+}
+
+fn main() {}
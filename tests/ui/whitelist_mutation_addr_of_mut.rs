@@ -0,0 +1,31 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::ptr::{addr_of, addr_of_mut};
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    value: u8,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        unsafe { addr_of_mut!(self.value).write(1) };
+    }
+
+    fn disallowed_function(&mut self) {
+        let ptr = addr_of_mut!(self.value);
+        //~^ ERROR: field `value` is mutated by `disallowed_function`, which is not allowed to mutate it
+        unsafe { ptr.write(2) };
+    }
+
+    fn core_path(&mut self) {
+        unsafe { core::ptr::addr_of_mut!(self.value).write(3) };
+        //~^ ERROR: field `value` is mutated by `core_path`, which is not allowed to mutate it
+    }
+
+    fn read_only(&self) -> u8 {
+        unsafe { addr_of!(self.value).read() }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("setter")]
+    field: u32,
+    #[clippy::mutatedby("set_other")]
+    other: u32,
+}
+
+mod helpers {
+    use super::TestStruct;
+
+    pub fn setter(value: &mut TestStruct) {
+        value.field = 1;
+    }
+
+    pub fn clear(value: &mut TestStruct) {
+        value.field = 0;
+        //~^ ERROR: field `field` is mutated by `clear`, which is not allowed to mutate it
+    }
+
+    pub fn update(value: &mut TestStruct) {
+        value.other = 2;
+        //~^ ERROR: field `other` is mutated by `update`, which is not allowed to mutate it
+    }
+}
+
+// The whitelist refers to the functions by their own name, regardless of how they are imported
+use helpers::{clear as setter_alias, setter as set, update as set_other};
+
+fn main() {
+    let mut value = TestStruct { field: 0, other: 0 };
+    set(&mut value);
+    setter_alias(&mut value);
+    set_other(&mut value);
+}
@@ -0,0 +1,56 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    option: Option<u8>,
+    #[clippy::mutatedby("allowed_function")]
+    result: Result<u8, ()>,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        if let Some(v) = self.option.as_mut() {
+            *v = 1;
+        }
+        if let Ok(v) = self.result.as_mut() {
+            *v = 1;
+        }
+    }
+
+    fn if_let_option(&mut self) {
+        if let Some(v) = self.option.as_mut() {
+            //~^ ERROR: field `option` is mutated by `if_let_option`, which is not allowed to mutate it
+            *v = 3;
+        }
+    }
+
+    fn if_let_result(&mut self) {
+        if let Ok(v) = self.result.as_mut() {
+            //~^ ERROR: field `result` is mutated by `if_let_result`, which is not allowed to mutate it
+            *v += 1;
+        }
+    }
+
+    fn while_let_option(&mut self) {
+        while let Some(v) = self.option.as_mut() {
+            //~^ ERROR: field `option` is mutated by `while_let_option`, which is not allowed to mutate it
+            if *v == 0 {
+                break;
+            }
+            *v -= 1;
+        }
+    }
+
+    fn match_ergonomics(&mut self) {
+        if let Some(v) = &mut self.option {
+            //~^ ERROR: field `option` is mutated by `match_ergonomics`, which is not allowed to mutate it
+            *v = 4;
+        }
+    }
+
+    fn as_ref(&self) -> u8 {
+        if let Some(v) = self.option.as_ref() { *v } else { 0 }
+    }
+}
+
+fn main() {}
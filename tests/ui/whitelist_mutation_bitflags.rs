@@ -0,0 +1,49 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+const READ: u8 = 0b001;
+const WRITE: u8 = 0b010;
+const EXEC: u8 = 0b100;
+
+pub struct Permissions {
+    #[clippy::mutatedby("grant", "revoke")]
+    flags: u8,
+}
+
+impl Permissions {
+    fn grant(&mut self, flag: u8) {
+        self.flags |= flag;
+    }
+
+    fn revoke(&mut self, flag: u8) {
+        self.flags &= !flag;
+    }
+
+    fn escalate(&mut self) {
+        self.flags |= WRITE | EXEC;
+        //~^ ERROR: field `flags` is mutated by `escalate`, which is not allowed to mutate it
+    }
+
+    fn restrict(&mut self) {
+        self.flags &= READ;
+        //~^ ERROR: field `flags` is mutated by `restrict`, which is not allowed to mutate it
+    }
+
+    fn toggle(&mut self, flag: u8) {
+        self.flags ^= flag;
+        //~^ ERROR: field `flags` is mutated by `toggle`, which is not allowed to mutate it
+    }
+
+    fn can_write(&self) -> bool {
+        self.flags & WRITE != 0
+    }
+
+    fn check(&mut self) -> bool {
+        if self.flags & EXEC != 0 {
+            self.flags & READ != 0
+        } else {
+            false
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,91 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct Denied {
+    #[clippy::not_mutatedby("read_only", "peek")]
+    field: u8,
+}
+
+impl Denied {
+    fn increment(&mut self) {
+        self.field += 1;
+    }
+
+    fn read_only(&mut self) {
+        self.field = 0;
+        //~^ ERROR: field `field` is mutated by `read_only`, which is not allowed to mutate it
+    }
+
+    fn peek(&mut self) -> u8 {
+        self.field
+    }
+}
+
+fn reset(value: &mut Denied) {
+    value.field = 0;
+}
+
+pub struct Both {
+    #[clippy::mutatedby("set")]
+    #[clippy::not_mutatedby("set")]
+    field: u8,
+}
+
+impl Both {
+    fn set(&mut self) {
+        self.field = 1;
+        //~^ ERROR: field `field` is mutated by `set`, which is not allowed to mutate it
+    }
+}
+
+pub struct Counter {
+    #[clippy::mutatedby("increment", "decrement", "reset", "double", "halve")]
+    //~^ ERROR: field `count` is mutated by 5 of the 6 methods of `Counter`
+    count: u32,
+}
+
+impl Counter {
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    fn decrement(&mut self) {
+        self.count -= 1;
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    fn double(&mut self) {
+        self.count *= 2;
+    }
+
+    fn halve(&mut self) {
+        self.count /= 2;
+    }
+
+    fn get(&self) -> u32 {
+        self.count
+    }
+}
+
+pub struct FewMutators {
+    #[clippy::mutatedby("increment", "reset")]
+    count: u32,
+}
+
+impl FewMutators {
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    fn get(&self) -> u32 {
+        self.count
+    }
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct Buffer {
+    #[clippy::not_mutatedby("Legacy::write", "Legacy::flush")]
+    data: Vec<u8>,
+}
+
+pub struct Legacy;
+
+impl Legacy {
+    fn write(&self, buffer: &mut Buffer) {
+        buffer.data.push(0);
+        //~^ ERROR: field `data` is mutated by `write`, which is not allowed to mutate it
+    }
+}
+
+pub struct New;
+
+impl New {
+    fn write(&self, buffer: &mut Buffer) {
+        buffer.data.push(1);
+    }
+}
+
+pub trait Flush {
+    fn flush(&self, buffer: &mut Buffer);
+}
+
+impl Flush for Legacy {
+    fn flush(&self, buffer: &mut Buffer) {
+        buffer.data.clear();
+        //~^ ERROR: field `data` is mutated by `flush`, which is not allowed to mutate it
+    }
+}
+
+impl Flush for New {
+    fn flush(&self, buffer: &mut Buffer) {
+        buffer.data.clear();
+    }
+}
+
+// Same name, but not a method of `Legacy`
+fn write(buffer: &mut Buffer) {
+    buffer.data.push(2);
+}
+
+fn main() {}
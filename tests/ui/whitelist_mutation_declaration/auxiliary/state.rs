@@ -0,0 +1,11 @@
+pub struct State {
+    #[clippy::mutatedby("reset")]
+    pub count: u32,
+    #[clippy::mutatedby("reset")]
+    pub names: Vec<String>,
+}
+
+pub fn reset(state: &mut State) {
+    state.count = 0;
+    state.names.clear();
+}
@@ -0,0 +1,28 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+// The restricted fields are declared in another file, which the notes point to
+#[path = "auxiliary/state.rs"]
+mod state;
+
+use state::State;
+
+fn bump(state: &mut State) {
+    state.count += 1;
+    //~^ ERROR: field `count` is mutated by `bump`
+}
+
+fn rename(state: &mut State) {
+    state.names.push(String::new());
+    //~^ ERROR: field `names` is mutated by `rename`
+}
+
+fn main() {
+    let mut s = State {
+        count: 0,
+        names: Vec::new(),
+    };
+    state::reset(&mut s);
+    bump(&mut s);
+    rename(&mut s);
+}
@@ -0,0 +1,41 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::ops::{Deref, DerefMut};
+
+pub struct Holder {
+    #[clippy::mutatedby("set")]
+    value: Box<u32>,
+}
+
+impl Holder {
+    fn set(&mut self, v: u32) {
+        *self.value.deref_mut() = v;
+    }
+
+    fn explicit(&mut self, v: u32) {
+        *self.value.deref_mut() = v;
+        //~^ ERROR: field `value` is mutated by `explicit`
+    }
+
+    fn ufcs(&mut self, v: u32) {
+        *DerefMut::deref_mut(&mut self.value) = v;
+        //~^ ERROR: field `value` is mutated by `ufcs`
+    }
+
+    fn later_write(&mut self) {
+        let value = self.value.deref_mut();
+        //~^ ERROR: field `value` is mutated by `later_write`
+        *value += 1;
+    }
+
+    fn read(&self) -> u32 {
+        *self.value.deref()
+    }
+}
+
+fn free(holder: &mut Holder) {
+    *holder.value.deref_mut() += 1;
+    //~^ ERROR: field `value` is mutated by `free`
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct Connection {
+    #[clippy::mutatedby("open")]
+    open: bool,
+    #[clippy::not_mutatedby("drop")]
+    denied: bool,
+}
+
+impl Connection {
+    fn open(&mut self) {
+        self.open = true;
+    }
+
+    // Only `Drop::drop` is allowed
+    fn drop(&mut self) {
+        self.open = false;
+        //~^ ERROR: field `open` is mutated by `drop`
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.open = false;
+        self.denied = true;
+        //~^ ERROR: field `denied` is mutated by `drop`
+    }
+}
+
+pub struct Pool {
+    connection: Connection,
+}
+
+impl Drop for Pool {
+    // The `Drop` impl of another type isn't allowed
+    fn drop(&mut self) {
+        self.connection.open = false;
+        //~^ ERROR: field `open` is mutated by `drop`
+    }
+}
+
+fn main() {}
@@ -0,0 +1,123 @@
+// Functions not mentioning a type with a restricted field are skipped without running the full
+// analysis. `#[clippy::dump_mutations]` prints whether each body was skipped, which none of the
+// functions reaching a restricted item indirectly may be.
+#![warn(clippy::fields_mutated_by_whitelist)]
+#![allow(static_mut_refs, clippy::ptr_arg)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+pub struct Counter {
+    #[clippy::mutatedby("increment")]
+    count: u32,
+}
+
+pub struct Unrelated {
+    count: u32,
+}
+
+#[clippy::mutatedby("bump")]
+static mut TOTAL: u32 = 0;
+
+impl Counter {
+    #[clippy::dump_mutations]
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+}
+
+#[clippy::dump_mutations]
+
+fn bump() {
+    unsafe { TOTAL += 1 };
+}
+
+// Unrelated functions
+
+#[clippy::dump_mutations]
+
+fn arithmetic(a: u32, b: u32) -> u32 {
+    let mut total = a;
+    total += b;
+    total * 2
+}
+
+#[clippy::dump_mutations]
+
+fn strings(names: &mut Vec<String>) {
+    names.push(String::from("name"));
+    names.sort();
+}
+
+#[clippy::dump_mutations]
+
+fn maps() -> HashMap<u32, u32> {
+    let mut map = HashMap::new();
+    map.insert(1, 2);
+    map
+}
+
+#[clippy::dump_mutations]
+
+fn unrelated(value: &mut Unrelated) {
+    value.count += 1;
+}
+
+#[clippy::dump_mutations]
+
+fn closures(values: &mut [u32]) {
+    values.iter_mut().for_each(|value| *value += 1);
+}
+
+#[clippy::dump_mutations]
+fn reads(counter: &Counter) -> u32 {
+    counter.count
+}
+
+// Functions reaching a restricted field indirectly
+
+#[clippy::dump_mutations]
+
+fn destructured_param(Counter { count }: &mut Counter) {
+    //~^ ERROR: field `count` is mutated by `destructured_param`
+    *count = 0;
+}
+
+#[clippy::dump_mutations]
+
+fn through_vec(counters: &mut Vec<Counter>) {
+    counters[0].count = 1;
+    //~^ ERROR: field `count` is mutated by `through_vec`
+}
+
+#[clippy::dump_mutations]
+
+fn through_box(counter: &mut Box<Counter>) {
+    counter.count = 2;
+    //~^ ERROR: field `count` is mutated by `through_box`
+}
+
+#[clippy::dump_mutations]
+
+fn through_refcell(counter: &RefCell<Counter>) {
+    counter.borrow_mut().count = 3;
+    //~^ ERROR: field `count` is mutated by `through_refcell`
+}
+
+#[clippy::dump_mutations]
+
+fn through_iterator<'a>(counters: impl Iterator<Item = &'a mut Counter>) {
+    for counter in counters {
+        counter.count = 4;
+        //~^ ERROR: field `count` is mutated by `through_iterator`
+    }
+}
+
+#[clippy::dump_mutations]
+
+fn static_write() {
+    unsafe { TOTAL = 0 };
+    //~^ ERROR: static `TOTAL` is mutated by `static_write`
+}
+
+fn main() {}
@@ -0,0 +1,50 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("set_*")]
+    glob: u32,
+    #[clippy::mutatedby("TestStruct::reset")]
+    qualified: u32,
+    #[clippy::mutatedby("?ump")]
+    single_char: u32,
+}
+
+impl TestStruct {
+    fn set_glob(&mut self) {
+        self.glob = 1;
+    }
+
+    fn reset(&mut self) {
+        self.qualified = 0;
+        self.glob = 0;
+        //~^ ERROR: field `glob` is mutated by `reset`, which is not allowed to mutate it
+    }
+
+    fn bump(&mut self) {
+        self.single_char += 1;
+    }
+
+    fn pump(&mut self) {
+        self.single_char += 2;
+    }
+
+    fn thump(&mut self) {
+        self.single_char += 3;
+        //~^ ERROR: field `single_char` is mutated by `thump`, which is not allowed to mutate it
+    }
+}
+
+struct Other;
+
+impl Other {
+    fn reset(value: &mut TestStruct) {
+        value.qualified = 0;
+        //~^ ERROR: field `qualified` is mutated by `reset`, which is not allowed to mutate it
+    }
+}
+
+fn set_free(value: &mut TestStruct) {
+    value.glob = 2;
+}
+
+fn main() {}
@@ -0,0 +1,50 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby(impl = "TestStruct")]
+    field: u8,
+    #[clippy::mutatedby(impl = "TestStruct", "reset")]
+    other: u8,
+    #[clippy::not_mutatedby(impl = "Other")]
+    open: u8,
+}
+
+impl TestStruct {
+    fn set(&mut self, value: u8) {
+        self.field = value;
+        self.other = value;
+    }
+}
+
+impl Default for TestStruct {
+    fn default() -> Self {
+        let mut value = Self {
+            field: 0,
+            other: 0,
+            open: 0,
+        };
+        value.field = 1;
+        value
+    }
+}
+
+fn reset(ts: &mut TestStruct) {
+    ts.other = 0;
+    ts.field = 0;
+    //~^ ERROR: field `field` is mutated by `reset`, which is not allowed to mutate it
+    ts.open = 0;
+}
+
+pub struct Other;
+
+impl Other {
+    fn modify(&self, ts: &mut TestStruct) {
+        ts.field = 1;
+        //~^ ERROR: field `field` is mutated by `modify`, which is not allowed to mutate it
+        ts.open = 1;
+        //~^ ERROR: field `open` is mutated by `modify`, which is not allowed to mutate it
+    }
+}
+
+fn main() {}
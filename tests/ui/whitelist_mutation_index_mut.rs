@@ -0,0 +1,66 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::ops::{Index, IndexMut};
+
+pub struct Slots {
+    values: [u8; 4],
+}
+
+impl Index<usize> for Slots {
+    type Output = u8;
+
+    fn index(&self, index: usize) -> &u8 {
+        &self.values[index]
+    }
+}
+
+impl IndexMut<usize> for Slots {
+    fn index_mut(&mut self, index: usize) -> &mut u8 {
+        &mut self.values[index]
+    }
+}
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    slots: Slots,
+    #[clippy::mutatedby("allowed_function")]
+    nested: (Slots, u8),
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        self.slots[0] = 1;
+        self.nested.0[1] += 1;
+    }
+
+    fn disallowed_assign(&mut self) {
+        self.slots[0] = 1;
+        //~^ ERROR: field `slots` is mutated by `disallowed_assign`
+    }
+
+    fn disallowed_compound_assign(&mut self) {
+        self.slots[1] += 1;
+        //~^ ERROR: field `slots` is mutated by `disallowed_compound_assign`
+    }
+
+    fn disallowed_nested(&mut self) {
+        self.nested.0[2] = 3;
+        //~^ ERROR: field `nested` is mutated by `disallowed_nested`
+    }
+
+    fn disallowed_borrow(&mut self) -> &mut u8 {
+        &mut self.slots[3]
+        //~^ ERROR: field `slots` is mutated by `disallowed_borrow`
+    }
+
+    fn disallowed_explicit_call(&mut self) {
+        *self.slots.index_mut(0) = 4;
+        //~^ ERROR: field `slots` is mutated by `disallowed_explicit_call`
+    }
+
+    fn read_only(&self) -> u8 {
+        self.slots[0] + self.nested.0[1]
+    }
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::cell::{Cell, RefCell};
+
+pub struct Inner {
+    #[clippy::mutatedby("allowed_function")]
+    count: Cell<u32>,
+}
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    buf: RefCell<Vec<u32>>,
+    #[clippy::mutatedby("allowed_function")]
+    count: Cell<u32>,
+    inner: Inner,
+}
+
+impl TestStruct {
+    fn allowed_function(&self) {
+        self.buf.borrow_mut().push(1);
+        self.count.set(1);
+        self.count.replace(2);
+        self.inner.count.set(3);
+    }
+
+    fn disallowed_borrow_mut(&self) {
+        self.buf.borrow_mut().push(1);
+        //~^ ERROR: field `buf` is mutated by `disallowed_borrow_mut`
+    }
+
+    fn disallowed_set(&self) {
+        self.count.set(1);
+        //~^ ERROR: field `count` is mutated by `disallowed_set`
+    }
+
+    fn disallowed_replace(&self) -> u32 {
+        self.count.replace(2)
+        //~^ ERROR: field `count` is mutated by `disallowed_replace`
+    }
+
+    fn disallowed_nested(&self) {
+        self.inner.count.set(3);
+        //~^ ERROR: field `count` is mutated by `disallowed_nested`
+    }
+
+    fn read_only(&self) -> u32 {
+        self.buf.borrow().len() as u32 + self.count.get() + self.inner.count.get()
+    }
+}
+
+fn disallowed_free_function(value: &TestStruct) {
+    value.count.set(0);
+    //~^ ERROR: field `count` is mutated by `disallowed_free_function`
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+// Functions are checked against the restrictions of all items, wherever they're defined.
+
+fn disallowed_before_struct(value: &mut TestStruct) {
+    value.field = 1;
+    //~^ ERROR: field `field` is mutated by `disallowed_before_struct`
+}
+
+fn allowed_before_struct(value: &mut TestStruct) {
+    value.field = 2;
+}
+
+impl TestStruct {
+    fn disallowed_method(&mut self) {
+        self.field = 3;
+        //~^ ERROR: field `field` is mutated by `disallowed_method`
+    }
+
+    // `other` is only restricted by the `may_mutate` attribute below
+    fn restricted_later(&mut self) {
+        self.other = 4;
+        //~^ ERROR: field `other` is mutated by `restricted_later`
+    }
+}
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_before_struct")]
+    field: u8,
+    other: u8,
+}
+
+impl TestStruct {
+    #[clippy::may_mutate(other)]
+    fn declared_later(&mut self) {
+        self.other = 5;
+    }
+}
+
+mod later {
+    pub struct Counter {
+        #[clippy::mutatedby("bump")]
+        pub count: u8,
+    }
+
+    pub fn bump(counter: &mut Counter) {
+        counter.count += 1;
+    }
+}
+
+fn uses_module_declared_later(counter: &mut later::Counter) {
+    counter.count = 0;
+    //~^ ERROR: field `count` is mutated by `uses_module_declared_later`
+}
+
+fn main() {}
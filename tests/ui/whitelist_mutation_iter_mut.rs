@@ -0,0 +1,56 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::collections::HashMap;
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    buf: Vec<u32>,
+    #[clippy::mutatedby("allowed_function")]
+    map: HashMap<u32, u32>,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        for v in self.buf.iter_mut() {
+            *v += 1;
+        }
+        for v in self.map.values_mut() {
+            *v += 1;
+        }
+    }
+
+    fn disallowed_iter_mut(&mut self) {
+        for v in self.buf.iter_mut() {
+            //~^ ERROR: field `buf` is mutated by `disallowed_iter_mut`, which is not allowed to mutate it
+            *v += 1;
+        }
+        for (_, v) in self.map.iter_mut() {
+            //~^ ERROR: field `map` is mutated by `disallowed_iter_mut`, which is not allowed to mutate it
+            *v += 1;
+        }
+    }
+
+    fn disallowed_for_loop(&mut self) {
+        for v in &mut self.buf {
+            //~^ ERROR: field `buf` is mutated by `disallowed_for_loop`, which is not allowed to mutate it
+            *v = 0;
+        }
+    }
+
+    fn read_only_iter(&self) -> u32 {
+        let mut sum = 0;
+        for v in self.buf.iter() {
+            sum += v;
+        }
+        for v in self.map.values() {
+            sum += v;
+        }
+        sum
+    }
+
+    fn read_only_iter_with_mut_self(&mut self) -> u32 {
+        self.buf.iter().sum::<u32>() + self.map.values().sum::<u32>()
+    }
+}
+
+fn main() {}
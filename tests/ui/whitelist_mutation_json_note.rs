@@ -0,0 +1,23 @@
+// The annotations are matched against the JSON diagnostics, the note must stay stable for tooling.
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    //~^ HELP: add `disallowed_function` to the `#[clippy::mutatedby]` attribute of `field`
+    field: u8,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        self.field = 1;
+    }
+
+    fn disallowed_function(&mut self) {
+        self.field = 2;
+        //~^ ERROR: field `field` is mutated by `disallowed_function`
+        //~| NOTE: field=field violated_by=disallowed_function
+        //~| NOTE: `-D clippy::fields-mutated-by-whitelist` implied by `-D warnings`
+    }
+}
+
+fn main() {}
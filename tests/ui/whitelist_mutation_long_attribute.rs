@@ -0,0 +1,29 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("set")]
+    short: u8,
+    // Adding to this attribute would make it longer than 100 characters, so only the name is given
+    #[clippy::mutatedby("first_setter", "second_setter", "third_setter", "fourth_setter", "reset")]
+    long: u8,
+}
+
+impl TestStruct {
+    fn set(&mut self) {
+        self.short = 1;
+    }
+
+    fn reset(&mut self) {
+        self.long = 0;
+    }
+
+    fn disallowed_function(&mut self) {
+        self.short = 2;
+        //~^ ERROR: field `short` is mutated by `disallowed_function`
+        self.long = 2;
+        //~^ ERROR: field `long` is mutated by `disallowed_function`
+    }
+}
+
+fn main() {}
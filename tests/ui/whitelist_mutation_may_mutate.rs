@@ -0,0 +1,47 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("field_side")]
+    field: u8,
+    #[clippy::mutatedby("field_side")]
+    other: u8,
+    unrestricted: u8,
+}
+
+impl TestStruct {
+    fn field_side(&mut self) {
+        self.field = 1;
+        self.other = 1;
+    }
+
+    #[clippy::may_mutate("field")]
+    fn function_side(&mut self) {
+        self.field = 2;
+    }
+
+    #[clippy::may_mutate("field")]
+    fn function_side_wrong_field(&mut self) {
+        self.other = 3; // Should trigger a lint warning
+    }
+
+    #[clippy::may_mutate("field", "other")]
+    fn claims_more_than_it_does(&mut self) {
+        self.field = 4;
+    }
+
+    #[clippy::may_mutate("unrestricted")]
+    fn restricts_unannotated_field(&mut self) {
+        self.unrestricted = 5;
+    }
+
+    fn disallowed_function(&mut self) {
+        self.field = 6; // Should trigger a lint warning
+        self.unrestricted = 6; // Should trigger a lint warning
+    }
+
+    #[clippy::may_mutate("missing")]
+    fn unknown_field(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+#[derive(Default)]
+pub struct Builder {
+    items: Vec<u32>,
+}
+
+impl Builder {
+    fn with(&mut self, item: u32) -> &mut Self {
+        self.items.push(item);
+        self
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    builder: Builder,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        self.builder.with(1).with(2);
+    }
+
+    fn builder_chain(&mut self) {
+        self.builder.with(1).with(2);
+        //~^ ERROR: field `builder` is mutated by `builder_chain`, which is not allowed to mutate it
+    }
+
+    fn chain_on_borrow(&mut self) {
+        let builder = &mut self.builder;
+        //~^ ERROR: field `builder` is mutated by `chain_on_borrow`, which is not allowed to mutate it
+        builder.with(3).with(4);
+    }
+
+    fn read_only(&self) -> usize {
+        self.builder.len()
+    }
+}
+
+fn main() {}
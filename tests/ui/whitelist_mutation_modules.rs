@@ -0,0 +1,51 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+// Both modules use the same names, but their restrictions must not affect each other.
+
+mod first {
+    pub struct TestStruct {
+        #[clippy::mutatedby("setter")]
+        field: u8,
+    }
+
+    impl TestStruct {
+        fn setter(&mut self) {
+            self.field = 1;
+        }
+
+        fn other(&mut self) {
+            self.field = 2;
+            //~^ ERROR: field `field` is mutated by `other`, which is not allowed to mutate it
+        }
+
+        #[clippy::may_mutate("field")]
+        //~^ ERROR: `declared` is declared to mutate `field`, but never does
+        fn declared(&mut self) {}
+    }
+}
+
+mod second {
+    pub struct TestStruct {
+        #[clippy::mutatedby("other")]
+        field: u8,
+    }
+
+    impl TestStruct {
+        fn setter(&mut self) {
+            self.field = 1;
+            //~^ ERROR: field `field` is mutated by `setter`, which is not allowed to mutate it
+        }
+
+        fn other(&mut self) {
+            self.field = 2;
+        }
+
+        #[clippy::may_mutate("field")]
+        fn declared(&mut self) {
+            self.field = 3;
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct Counter {
+    #[clippy::mutatedby("increment", "reset")]
+    count: u32,
+    #[clippy::mutatedby("increment", "reset")]
+    total: u32,
+}
+
+impl Counter {
+    #[clippy::may_mutate(count, totl)]
+    //~^ ERROR: `totl` is not a field of the `Self` type of `increment`
+    fn increment(&mut self) {
+        self.count += 1;
+        self.total += 1;
+    }
+
+    #[clippy::may_mutate("count", "tota", "total")]
+    //~^ ERROR: `tota` is not a field of the `Self` type of `reset`
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct MyStruct {
+    #[clippy::mutatedby("Manager::apply")]
+    state: u32,
+}
+
+pub struct Manager;
+
+impl Manager {
+    fn apply(owner: &mut MyStruct) {
+        owner.state += 1;
+    }
+
+    fn reset(owner: &mut MyStruct) {
+        owner.state = 0;
+        //~^ ERROR: field `state` is mutated by `reset`, which is not allowed to mutate it
+    }
+}
+
+mod helpers {
+    pub struct Manager;
+
+    impl Manager {
+        // The entry refers to `crate::Manager::apply` just as well, as leading segments may be left out
+        pub fn apply(owner: &mut super::MyStruct) {
+            owner.state = 2;
+        }
+    }
+}
+
+pub struct Other;
+
+impl Other {
+    fn apply(owner: &mut MyStruct) {
+        owner.state = 3;
+        //~^ ERROR: field `state` is mutated by `apply`, which is not allowed to mutate it
+    }
+}
+
+impl MyStruct {
+    fn apply(&mut self) {
+        self.state = 4;
+        //~^ ERROR: field `state` is mutated by `apply`, which is not allowed to mutate it
+    }
+}
+
+fn apply(owner: &mut MyStruct) {
+    owner.state = 5;
+    //~^ ERROR: field `state` is mutated by `apply`, which is not allowed to mutate it
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("crate::setters::*")]
+    value: u32,
+}
+
+mod setters {
+    use super::TestStruct;
+
+    pub fn set(target: &mut TestStruct, value: u32) {
+        target.value = value;
+    }
+
+    pub mod nested {
+        pub fn reset(target: &mut super::TestStruct) {
+            target.value = 0;
+        }
+    }
+
+    pub struct Setter;
+
+    impl Setter {
+        pub fn apply(target: &mut TestStruct) {
+            target.value += 1;
+        }
+    }
+}
+
+mod other {
+    use super::TestStruct;
+
+    pub fn set(target: &mut TestStruct) {
+        target.value = 1;
+        //~^ ERROR: field `value` is mutated by `set`, which is not allowed to mutate it
+    }
+
+    // Only the `setters` module at the crate root is allowed
+    pub mod setters {
+        pub fn set(target: &mut super::TestStruct) {
+            target.value = 2;
+            //~^ ERROR: field `value` is mutated by `set`, which is not allowed to mutate it
+        }
+    }
+}
+
+impl TestStruct {
+    fn set(&mut self) {
+        self.value = 3;
+        //~^ ERROR: field `value` is mutated by `set`, which is not allowed to mutate it
+    }
+}
+
+fn main() {
+    let mut value = TestStruct { value: 0 };
+    setters::set(&mut value, 1);
+    setters::nested::reset(&mut value);
+    setters::Setter::apply(&mut value);
+    other::set(&mut value);
+    other::setters::set(&mut value);
+    value.set();
+}
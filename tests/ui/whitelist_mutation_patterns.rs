@@ -0,0 +1,67 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+#![allow(clippy::match_single_binding)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    field: u8,
+    other: u8,
+}
+
+pub struct TupleStruct(u8, #[clippy::mutatedby("allowed_function")] u8, u8);
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        let TestStruct { ref mut field, .. } = *self;
+        *field = 1;
+    }
+
+    fn ref_mut_destructure(&mut self) {
+        let TestStruct { ref mut field, .. } = *self;
+        *field = 2;
+    }
+
+    fn match_ergonomics(&mut self) {
+        let Self { field, .. } = self;
+        *field = 3;
+    }
+
+    fn match_arm(&mut self) {
+        match self {
+            TestStruct { field, .. } => *field = 4,
+        }
+    }
+
+    fn other_field_only(&mut self) {
+        let TestStruct { ref mut other, .. } = *self;
+        *other = 5;
+    }
+
+    fn ref_binding(&mut self) -> u8 {
+        let TestStruct { ref field, .. } = *self;
+        *field
+    }
+}
+
+impl TupleStruct {
+    fn allowed_function(&mut self) {
+        let TupleStruct(_, ref mut value, _) = *self;
+        *value = 1;
+    }
+
+    fn tuple_destructure(&mut self) {
+        let TupleStruct(_, ref mut value, _) = *self;
+        *value = 2;
+    }
+
+    fn tuple_rest(&mut self) {
+        let TupleStruct(.., ref mut value, _) = *self;
+        *value = 3;
+    }
+
+    fn tuple_rest_other_fields(&mut self) {
+        let TupleStruct(ref mut first, ..) = *self;
+        *first = 4;
+    }
+}
+
+fn main() {}
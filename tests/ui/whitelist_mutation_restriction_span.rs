@@ -0,0 +1,29 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    field: u8,
+    // Only restricted through `may_mutate`, so there is no attribute to point to
+    other: u8,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        self.field = 1;
+    }
+
+    #[clippy::may_mutate("other")]
+    fn other_setter(&mut self) {
+        self.other = 1;
+    }
+
+    fn disallowed_function(&mut self) {
+        self.field = 2;
+        //~^ ERROR: field `field` is mutated by `disallowed_function`
+        self.other = 2;
+        //~^ ERROR: field `other` is mutated by `disallowed_function`
+    }
+}
+
+fn main() {}
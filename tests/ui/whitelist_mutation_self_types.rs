@@ -0,0 +1,50 @@
+//@no-rustfix
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::pin::Pin;
+use std::rc::Rc;
+
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    field: u8,
+}
+
+impl TestStruct {
+    #[allow(clippy::needless_arbitrary_self_type)]
+    fn allowed_function(self: &mut Self) {
+        self.field = 1;
+    }
+
+    #[allow(clippy::needless_arbitrary_self_type)]
+    fn explicit_self_type(self: &mut Self) {
+        self.field = 2;
+        //~^ ERROR: field `field` is mutated by `explicit_self_type`, which is not allowed
+    }
+
+    fn boxed_self(self: &mut Box<Self>) {
+        self.field = 3;
+        //~^ ERROR: field `field` is mutated by `boxed_self`, which is not allowed to mutate it
+    }
+
+    fn pinned_self(self: Pin<&mut Self>) {
+        self.get_mut().field = 4;
+        //~^ ERROR: field `field` is mutated by `pinned_self`, which is not allowed to mutate it
+    }
+
+    fn pinned_deref(mut self: Pin<&mut Self>) {
+        self.field += 5;
+        //~^ ERROR: field `field` is mutated by `pinned_deref`, which is not allowed to mutate it
+    }
+
+    fn owned_self(mut self) -> Self {
+        self.field = 6;
+        //~^ ERROR: field `field` is mutated by `owned_self`, which is not allowed to mutate it
+        self
+    }
+
+    fn rc_self(self: Rc<Self>) -> u8 {
+        self.field
+    }
+}
+
+fn main() {}
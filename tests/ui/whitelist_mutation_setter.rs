@@ -0,0 +1,52 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct TestStruct {
+    #[clippy::mutatedby("set_value", "reset")]
+    value: u32,
+    #[clippy::mutatedby("set_name", "set_name_from_str")]
+    name: String,
+    #[clippy::mutatedby("set_flag")]
+    flag: bool,
+}
+
+impl TestStruct {
+    fn set_value(&mut self, value: u32) {
+        self.value = value;
+    }
+
+    fn reset(&mut self) {
+        self.value = 0;
+    }
+
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    fn set_name_from_str(&mut self, name: String) {
+        self.name = name;
+    }
+
+    // Takes a value of another type
+    fn set_flag(&mut self, flag: u8) {
+        self.flag = flag != 0;
+    }
+
+    fn disallowed_value(&mut self) {
+        self.value = 1;
+        //~^ ERROR: field `value` is mutated by `disallowed_value`
+    }
+
+    fn disallowed_name(&mut self) {
+        // Several setters, none is suggested
+        self.name = String::new();
+        //~^ ERROR: field `name` is mutated by `disallowed_name`
+    }
+
+    fn disallowed_flag(&mut self) {
+        // No setter taking a `bool`
+        self.flag = true;
+        //~^ ERROR: field `flag` is mutated by `disallowed_flag`
+    }
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+#![allow(unused_assignments, clippy::no_effect, clippy::unnecessary_operation)]
+
+pub struct Counter {
+    #[clippy::mutatedby("increment")]
+    count: u32,
+}
+
+impl Counter {
+    fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    // Locals sharing the name of the field are unrelated to it
+    fn local_named_like_field(&mut self) -> u32 {
+        let mut count = self.count;
+        count = 3;
+        count += 1;
+        count
+    }
+
+    fn shadowed(&self) -> u32 {
+        let count = 1;
+        let mut count = count + self.count;
+        count *= 2;
+        count
+    }
+
+    fn destructured_copy(&self) -> u32 {
+        let Counter { mut count } = *self;
+        count += 1;
+        count
+    }
+
+    fn closure_param(&self) -> u32 {
+        let bump = |mut count: u32| {
+            count += 1;
+            count
+        };
+        bump(self.count)
+    }
+
+    fn parameter(&self, mut count: u32) -> u32 {
+        count = count.max(self.count);
+        count
+    }
+
+    // Binding the field by reference still mutates it
+    fn destructured_ref(&mut self) {
+        let Counter { count } = self;
+        //~^ ERROR: field `count` is mutated by `destructured_ref`
+        *count = 3;
+    }
+}
+
+fn free_function(counter: &Counter) -> u32 {
+    let mut count = counter.count;
+    count = 0;
+    count
+}
+
+fn main() {}
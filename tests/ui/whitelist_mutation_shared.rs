@@ -0,0 +1,55 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct State {
+    #[clippy::mutatedby("State::set_count")]
+    count: u32,
+    label: String,
+}
+
+impl State {
+    fn set_count(&mut self, count: u32) {
+        self.count = count;
+    }
+}
+
+fn assign(shared: &Rc<RefCell<State>>) {
+    shared.borrow_mut().count = 1;
+    //~^ ERROR: field `count` is mutated by `assign`
+}
+
+fn add_assign(shared: Rc<RefCell<State>>) {
+    shared.borrow_mut().count += 1;
+    //~^ ERROR: field `count` is mutated by `add_assign`
+}
+
+fn through_guard(shared: &Rc<RefCell<State>>) {
+    let mut state = shared.borrow_mut();
+    state.count = 2;
+    //~^ ERROR: field `count` is mutated by `through_guard`
+    state.label.push('a');
+}
+
+// The allowed method is called through the `RefMut`
+fn through_method(shared: &Rc<RefCell<State>>) {
+    shared.borrow_mut().set_count(3);
+}
+
+// FIXME: mutations of places which aren't captured from the enclosing function aren't seen
+// inside closures
+fn in_closure(shared: Rc<RefCell<State>>) -> impl Fn() {
+    move || shared.borrow_mut().count = 4
+}
+
+fn main() {
+    let shared = Rc::new(RefCell::new(State {
+        count: 0,
+        label: String::new(),
+    }));
+    shared.borrow_mut().count = 5;
+    //~^ ERROR: field `count` is mutated by `main`
+    let _ = shared.borrow().count;
+    assign(&shared);
+}
@@ -0,0 +1,52 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct Data {
+    #[clippy::mutatedby("reorder")]
+    data: Vec<u32>,
+    #[clippy::mutatedby("reorder")]
+    boxed: Box<[u32]>,
+}
+
+impl Data {
+    fn reorder(&mut self) {
+        self.data.sort();
+        self.boxed.reverse();
+    }
+
+    fn sort(&mut self) {
+        self.data.sort();
+        //~^ ERROR: field `data` is mutated by `sort`
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.data.swap(i, j);
+        //~^ ERROR: field `data` is mutated by `swap`
+    }
+
+    fn reverse(&mut self) {
+        self.data.reverse();
+        //~^ ERROR: field `data` is mutated by `reverse`
+    }
+
+    fn fill_boxed(&mut self) {
+        self.boxed.fill(0);
+        //~^ ERROR: field `boxed` is mutated by `fill_boxed`
+    }
+
+    fn iter_mut(&mut self) {
+        for value in self.data.iter_mut() {
+            //~^ ERROR: field `data` is mutated by `iter_mut`
+            *value += 1;
+        }
+    }
+
+    fn find(&self, value: u32) -> Result<usize, usize> {
+        self.data.binary_search(&value)
+    }
+
+    fn read_only(&self) -> bool {
+        self.data.is_empty() && self.data.contains(&1) && self.boxed.first().is_some()
+    }
+}
+
+fn main() {}
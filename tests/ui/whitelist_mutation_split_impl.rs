@@ -0,0 +1,44 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+// The restrictions are found through the type of the field, wherever its impls are.
+
+mod a {
+    pub struct S {
+        #[clippy::mutatedby("set")]
+        pub(crate) value: u8,
+        #[clippy::mutatedby("S::reset")]
+        pub(crate) qualified: u8,
+    }
+}
+
+mod b {
+    use crate::a::S;
+
+    impl S {
+        pub fn set(&mut self, value: u8) {
+            self.value = value;
+        }
+
+        pub fn reset(&mut self) {
+            self.qualified = 0;
+            self.value = 0;
+            //~^ ERROR: field `value` is mutated by `reset`, which is not allowed to mutate it
+        }
+    }
+
+    pub mod nested {
+        impl crate::a::S {
+            pub fn bump(&mut self) {
+                self.qualified += 1;
+                //~^ ERROR: field `qualified` is mutated by `bump`
+            }
+        }
+    }
+}
+
+fn main() {
+    let mut s = a::S { value: 0, qualified: 0 };
+    s.set(1);
+    s.reset();
+    s.bump();
+}
@@ -0,0 +1,52 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+pub struct State {
+    pub value: u32,
+}
+
+#[clippy::mutatedby("bump")]
+static mut COUNTER: u32 = 0;
+
+#[clippy::mutatedby("reset_state")]
+static mut STATE: State = State { value: 0 };
+
+#[clippy::mutatedby("fill")]
+static mut BUFFER: [u8; 4] = [0; 4];
+
+static mut UNRESTRICTED: u32 = 0;
+
+fn bump() {
+    unsafe { COUNTER += 1 };
+}
+
+fn reset_state() {
+    unsafe { STATE = State { value: 0 } };
+}
+
+fn fill() {
+    unsafe { BUFFER[0] = 1 };
+}
+
+fn disallowed() {
+    unsafe {
+        COUNTER = 5;
+        //~^ ERROR: static `COUNTER` is mutated by `disallowed`, which is not allowed to mutate it
+        STATE.value = 1;
+        //~^ ERROR: static `STATE` is mutated by `disallowed`, which is not allowed to mutate it
+        BUFFER[1] = 2;
+        //~^ ERROR: static `BUFFER` is mutated by `disallowed`, which is not allowed to mutate it
+        UNRESTRICTED = 1;
+    }
+}
+
+fn in_closure() {
+    let f = || unsafe { COUNTER += 2 };
+    //~^ ERROR: static `COUNTER` is mutated by `in_closure`, which is not allowed to mutate it
+    f();
+}
+
+fn reads() -> u32 {
+    unsafe { COUNTER + STATE.value + u32::from(BUFFER[0]) }
+}
+
+fn main() {}
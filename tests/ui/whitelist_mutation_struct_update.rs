@@ -0,0 +1,43 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+#[derive(Clone, Default)]
+pub struct TestStruct {
+    #[clippy::mutatedby("allowed_function")]
+    field: u8,
+    other: u8,
+}
+
+impl TestStruct {
+    fn allowed_function(&mut self) {
+        self.field = 1;
+    }
+
+    // Functional update syntax constructs a new value, it doesn't mutate `self`
+    fn with_field(&self, field: u8) -> Self {
+        Self { field, ..*self }
+    }
+
+    fn with_field_cloned(&self, field: u8) -> Self {
+        Self { field, ..self.clone() }
+    }
+
+    fn with_other(&self, other: u8) -> Self {
+        Self { other, ..*self }
+    }
+
+    fn with_field_default(field: u8) -> Self {
+        Self {
+            field,
+            ..Default::default()
+        }
+    }
+
+    fn mutated_after_update(&self) -> Self {
+        let mut value = Self { other: 1, ..*self };
+        value.field = 2;
+        //~^ ERROR: field `field` is mutated by `mutated_after_update`
+        value
+    }
+}
+
+fn main() {}
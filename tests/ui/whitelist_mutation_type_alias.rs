@@ -0,0 +1,51 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::collections::HashMap;
+use std::ops::AddAssign;
+
+type Counter = u32;
+type Names = Vec<String>;
+type Map<V> = HashMap<String, V>;
+
+pub struct S {
+    #[clippy::mutatedby("inc")]
+    c: Counter,
+    #[clippy::mutatedby("inc")]
+    names: Names,
+    #[clippy::mutatedby("inc")]
+    scores: Map<u32>,
+}
+
+impl S {
+    fn inc(&mut self) {
+        self.c += 1;
+        self.names.push(String::new());
+        self.scores.insert(String::new(), 1);
+    }
+
+    fn assign(&mut self) {
+        self.c = 0;
+        //~^ ERROR: field `c` is mutated by `assign`
+    }
+
+    fn method_calls(&mut self) {
+        self.c.add_assign(1);
+        //~^ ERROR: field `c` is mutated by `method_calls`
+        self.names.push(String::new());
+        //~^ ERROR: field `names` is mutated by `method_calls`
+        self.scores.entry(String::new()).or_insert(0);
+        //~^ ERROR: field `scores` is mutated by `method_calls`
+    }
+
+    fn through_ref(&mut self) {
+        let names: &mut Names = &mut self.names;
+        //~^ ERROR: field `names` is mutated by `through_ref`
+        names.clear();
+    }
+
+    fn read_only(&self) -> usize {
+        self.names.len() + self.scores.len() + self.c.count_ones() as usize
+    }
+}
+
+fn main() {}
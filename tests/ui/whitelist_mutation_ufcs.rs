@@ -0,0 +1,53 @@
+#![warn(clippy::fields_mutated_by_whitelist)]
+
+use std::ops::AddAssign;
+
+pub trait Rename {
+    fn rename(&mut self);
+    fn reset(&mut self);
+}
+
+pub struct MyStruct {
+    #[clippy::mutatedby("rename")]
+    name: String,
+    #[clippy::mutatedby("rename")]
+    count: u32,
+}
+
+impl Rename for MyStruct {
+    fn rename(&mut self) {
+        self.name = String::from("renamed");
+        self.count += 1;
+    }
+
+    fn reset(&mut self) {
+        self.name.clear();
+        //~^ ERROR: field `name` is mutated by `reset`
+    }
+}
+
+impl MyStruct {
+    fn add(&mut self) {
+        <u32 as AddAssign<u32>>::add_assign(&mut self.count, 1);
+        //~^ ERROR: field `count` is mutated by `add`
+    }
+}
+
+// The mutations happen in the trait methods, whichever way they are called
+fn calls(s: &mut MyStruct) {
+    <MyStruct as Rename>::rename(s);
+    <MyStruct as Rename>::reset(s);
+    Rename::rename(s);
+    MyStruct::rename(s);
+    s.rename();
+}
+
+fn main() {
+    let mut s = MyStruct {
+        name: String::new(),
+        count: 0,
+    };
+    <MyStruct as Rename>::rename(&mut s);
+    calls(&mut s);
+    s.add();
+}
@@ -0,0 +1,44 @@
+#![warn(clippy::zip_explicit_iter)]
+#![allow(clippy::useless_vec)]
+
+use std::collections::HashMap;
+
+struct Holder {
+    items: Vec<u32>,
+}
+
+fn main() {
+    let a = vec![1, 2, 3];
+    let b = vec![4, 5, 6];
+    let _ = a.iter().zip(b.iter());
+    //~^ ERROR: `zip` accepts any `IntoIterator`, calling `iter` is unnecessary
+
+    // A reference is `Copy` and iterates over the same items
+    let slice: &[u32] = &b;
+    let _ = a.iter().zip(slice.iter());
+    //~^ ERROR: `zip` accepts any `IntoIterator`, calling `iter` is unnecessary
+
+    // An array passed by value would yield the elements themselves
+    let array = [7, 8, 9];
+    let _ = a.iter().zip(array.iter());
+    //~^ ERROR: `zip` accepts any `IntoIterator`, calling `iter` is unnecessary
+
+    let holder = Holder { items: vec![1] };
+    let _ = a.iter().zip(holder.items.iter());
+    //~^ ERROR: `zip` accepts any `IntoIterator`, calling `iter` is unnecessary
+
+    let map: HashMap<u32, u32> = HashMap::new();
+    let _ = a.iter().zip(map.iter());
+    //~^ ERROR: `zip` accepts any `IntoIterator`, calling `iter` is unnecessary
+
+    let r = &b;
+    let _ = a.iter().zip(r.iter());
+    //~^ ERROR: `zip` accepts any `IntoIterator`, calling `iter` is unnecessary
+
+    // Not `iter`
+    let _ = a.iter().zip(b.iter().rev());
+    let _ = a.iter().zip(b.clone());
+
+    // `str` has no `IntoIterator` implementation
+    let _ = a.iter().zip("abc".chars());
+}